@@ -13,6 +13,7 @@ import { NetworkDiagramComponent } from './features/visualizations/network-diagr
 import { TreemapComponent } from './features/visualizations/treemap/treemap.component';
 import { LoadDashboardComponent } from './features/visualizations/load-dashboard/load-dashboard.component';
 import { LoadConfigComponent } from './features/visualizations/load-config/load-config.component';
+import { StatsComponent } from './features/visualizations/stats/stats.component';
 import { connectionGuard } from './core/guards/connection.guard';
 
 export const routes: Routes = [
@@ -33,4 +34,5 @@ export const routes: Routes = [
   { path: 'visualizations/treemap', component: TreemapComponent, canActivate: [connectionGuard] },
   { path: 'visualizations/load', component: LoadDashboardComponent, canActivate: [connectionGuard] },
   { path: 'visualizations/load/config', component: LoadConfigComponent, canActivate: [connectionGuard] },
+  { path: 'visualizations/stats', component: StatsComponent, canActivate: [connectionGuard] },
 ];
@@ -2,11 +2,63 @@ import { TeamMember } from './team-member.model';
 import { Topic } from './topic.model';
 import { Tag } from './tag.model';
 
+/**
+ * Workspace-wide RACI defaults that are added to new topics (e.g. department head always I).
+ */
+export interface RaciDefaults {
+  cMemberIds: string[];
+  iMemberIds: string[];
+}
+
+/**
+ * Workspace rules for topic references (shared file paths and file numbers).
+ */
+export interface ReferencePolicy {
+  sharedPathPrefixes?: string[]; // Allowed path prefixes; empty allows every valid path
+  fileNumberPattern?: string; // Regular expression the whole file number must match
+  fileNumberUppercase?: boolean; // Convert file numbers to upper case
+  fileNumberUnique?: boolean; // File numbers must be unique across topics
+}
+
+/**
+ * Stop words ignored by the search (index and query).
+ */
+export interface SearchStopWords {
+  useGermanList: boolean; // Include the built-in list of common German words
+  custom: string[]; // Domain-specific words, e.g. department abbreviations
+}
+
+export type AutoRuleField = 'header' | 'keywords' | 'description' | 'any';
+
+/**
+ * Rule for automatic classification: if the field contains the text, the actions are applied.
+ */
+export interface AutoRule {
+  id: string; // UUID
+  name: string;
+  field: AutoRuleField;
+  contains: string; // Case-insensitive text the field must contain
+  addTags?: string[]; // Managed tag names to add
+  setPriority?: number; // Priority (1-10), only set if the topic has none
+  enabled: boolean;
+}
+
 export interface Datastore {
   schemaVersion: number;
   generatedAt: string; // ISO timestamp
   revisionId: number;
+  /**
+   * Identifies this datastore lineage (UUID). Generated when the datastore is created.
+   * Clients force a full reload when it changes, e.g. after a different datastore was connected.
+   */
+  instanceId?: string;
+  /** Next sequence number for human-readable topic keys (e.g. "RACI-142") */
+  nextTopicNumber?: number;
   members: TeamMember[];
   topics: Topic[];
   tags?: Tag[]; // Managed tags
+  raciDefaults?: RaciDefaults;
+  autoRules?: AutoRule[];
+  referencePolicy?: ReferencePolicy;
+  searchStopWords?: SearchStopWords;
 }
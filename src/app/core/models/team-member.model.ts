@@ -1,3 +1,12 @@
+export type AbsenceType = 'vacation' | 'sick' | 'other';
+
+export interface MemberAbsence {
+  from: string; // Date string "YYYY-MM-DD" (inclusive)
+  to: string; // Date string "YYYY-MM-DD" (inclusive)
+  type: AbsenceType;
+  note?: string;
+}
+
 export interface TeamMember {
   id: string; // UUID
   displayName: string;
@@ -6,6 +15,9 @@ export interface TeamMember {
   tags?: string[];
   color?: string; // Hex color code (e.g., '#FF5733')
   updatedAt: string; // ISO timestamp
+  createdBy?: string; // Member ID who created the member entry
+  updatedBy?: string; // Member ID who last edited the member entry
+  absences?: MemberAbsence[]; // Planned or current absences (vacation, sick leave)
   
   // Load configuration (optional, stored per member)
   /** Part-time factor: 1.0 = full-time, 0.8 = 80%, etc. Range: (0, 1] */
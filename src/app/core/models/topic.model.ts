@@ -10,6 +10,7 @@ export interface TopicRaci {
   r3MemberId?: string;
   cMemberIds: string[];
   iMemberIds: string[];
+  escalationMemberIds?: string[]; // Ordered escalation contacts after R3
 }
 
 export type TShirtSize = 'XXS' | 'XS' | 'S' | 'M' | 'L' | 'XL' | 'XXL';
@@ -43,6 +44,39 @@ export interface IrregularTaskEstimation {
   waveClass: WaveClass;
 }
 
+/**
+ * Temporary transfer of the responsibility for a topic to another member.
+ * Dates are inclusive calendar dates ("YYYY-MM-DD").
+ */
+export interface TopicDelegation {
+  memberId: string; // Member the topic is delegated to
+  from: string;
+  to: string;
+  reason?: string;
+}
+
+/**
+ * Change of the T-shirt size (effort estimate) of a topic.
+ */
+export interface TopicSizeChange {
+  from?: TShirtSize;
+  to?: TShirtSize;
+  changedAt: string; // ISO timestamp
+  changedBy?: string; // Member ID who changed the size
+}
+
+export type RotationPeriod = 'weekly' | 'monthly';
+
+/**
+ * Rotation of the responsibility for a topic through a list of members
+ * (e.g. weekly on-call duty). The first member is in charge from startDate on.
+ */
+export interface TopicRotation {
+  memberIds: string[]; // Members in rotation order
+  period: RotationPeriod;
+  startDate: string; // Date string "YYYY-MM-DD"
+}
+
 /**
  * Type of connection between two topics.
  * - dependsOn: This topic depends on another topic
@@ -62,19 +96,26 @@ export interface TopicConnection {
 export interface Topic {
   id: string; // UUID
   header: string;
+  key?: string; // Human-readable, immutable display key (e.g. "RACI-142")
+  slug?: string; // URL slug derived from the header (unique)
+  slugAliases?: string[]; // Previous slugs, kept so old links keep resolving
   description?: string;
+  links?: string[]; // Outbound links extracted from the description
   tags?: string[];
   searchKeywords?: string[];
   validity: TopicValidity;
   notes?: string;
   raci: TopicRaci;
   updatedAt: string; // ISO timestamp
+  createdBy?: string; // Member ID who created the topic
+  updatedBy?: string; // Member ID who last edited the topic
   priority?: number; // Star rating from 1 to 10
   hasFileNumber?: boolean; // "hat Aktenzeichen"
   fileNumber?: string; // File number, shown when hasFileNumber is true
   hasSharedFilePath?: boolean; // "hat Ablageort"
   sharedFilePath?: string; // Shared file path, shown when hasSharedFilePath is true
   size?: TShirtSize; // T-shirt size classification (XXS to XXL)
+  sizeHistory?: TopicSizeChange[]; // Changes of size, oldest first
   connections?: TopicConnection[]; // Connections to other topics
   taskCategory?: TaskCategory; // Default: 'REGULAR'
   irregularEstimation?: IrregularTaskEstimation;
@@ -83,4 +124,6 @@ export interface Topic {
    * but no direct effort (effort comes from child/leaf topics).
    */
   topicType?: TopicType;
+  delegation?: TopicDelegation; // Temporary responsibility transfer
+  rotation?: TopicRotation; // Rotating responsibility (takes precedence over R1)
 }
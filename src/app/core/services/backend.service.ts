@@ -1,6 +1,6 @@
 import { Injectable } from '@angular/core';
 import { Observable } from 'rxjs';
-import { Datastore, Topic, TeamMember, Tag, LockPurpose } from '../models';
+import { Datastore, Topic, TeamMember, Tag, LockPurpose, RaciDefaults, AutoRule, ReferencePolicy, SearchStopWords } from '../models';
 
 /**
  * Abstract backend interface.
@@ -65,6 +65,12 @@ export abstract class BackendService {
   // Batch operations for quick assignment
   abstract updateMultipleTopics(updates: Array<{ topicId: string; changes: Partial<Topic> }>): Promise<boolean>;
 
+  // Workspace settings
+  abstract updateRaciDefaults(raciDefaults: RaciDefaults): Promise<boolean>;
+  abstract updateAutoRules(autoRules: AutoRule[]): Promise<boolean>;
+  abstract updateReferencePolicy(referencePolicy: ReferencePolicy): Promise<boolean>;
+  abstract updateSearchStopWords(searchStopWords: SearchStopWords): Promise<boolean>;
+
   // Utility
   abstract generateUUID(): string;
 }
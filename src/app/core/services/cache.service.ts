@@ -1,7 +1,12 @@
 import { Injectable, signal, computed, inject, DestroyRef } from '@angular/core';
 import { BehaviorSubject, Observable, Subject, interval } from 'rxjs';
 import { takeUntilDestroyed } from '@angular/core/rxjs-interop';
-import { Datastore, Topic, TeamMember, Tag } from '../models';
+import { AutoRule, Datastore, RaciDefaults, ReferencePolicy, SearchStopWords, Topic, TeamMember, Tag } from '../models';
+import { applyAutoRules, hasRuleInputChanged, replaceRuleTag } from '../../shared/utils/auto-rules.utils';
+import { normalizeTopicDescription } from '../../shared/utils/description.utils';
+import { recordSizeChange } from '../../shared/utils/size-history.utils';
+import { assignMissingSlugs, collectUsedSlugs, createUniqueSlug, updateTopicSlug } from '../../shared/utils/slug.utils';
+import { assignMissingTopicKeys, formatTopicKey, getNextTopicNumber } from '../../shared/utils/topic-key.utils';
 
 /**
  * Conflict resolution strategy when backend and cache differ.
@@ -73,6 +78,9 @@ export class CacheService {
   readonly isSyncing = signal(false);
   readonly lastError = signal<string | null>(null);
 
+  /** Member ID of the current user, stamped into createdBy/updatedBy */
+  private currentMemberId = '';
+
   // Computed signals
   readonly isDirty = computed(() => this.cacheStateSubject.value.isDirty);
   readonly pendingChangesCount = signal(0);
@@ -116,15 +124,23 @@ export class CacheService {
     return this.cacheStateSubject.value.isDirty;
   }
 
+  /**
+   * Set the member recorded as author of subsequent topic and member changes.
+   */
+  setCurrentUser(memberId: string): void {
+    this.currentMemberId = memberId;
+  }
+
   // ==================== CACHE INITIALIZATION ====================
 
   /**
    * Initialize cache with datastore from backend.
    * Called after backend connection is established.
+   * Topics without a key or slug get one; these are persisted with the next save.
    */
   initializeFromBackend(datastore: Datastore): void {
     this.cacheStateSubject.next({
-      datastore: this.deepClone(datastore),
+      datastore: assignMissingSlugs(assignMissingTopicKeys(this.deepClone(datastore))),
       isDirty: false,
       lastSyncTime: new Date().toISOString(),
       revisionId: datastore.revisionId
@@ -155,9 +171,17 @@ export class CacheService {
       return { success: false, message: 'Cache nicht initialisiert' };
     }
 
+    const topicNumber = getNextTopicNumber(state.datastore);
     const updatedDatastore: Datastore = {
       ...state.datastore,
-      topics: [...state.datastore.topics, topic],
+      topics: [...state.datastore.topics, applyAutoRules(normalizeTopicDescription({
+        ...topic,
+        key: formatTopicKey(topicNumber),
+        slug: createUniqueSlug(topic.header, collectUsedSlugs(state.datastore.topics)),
+        slugAliases: undefined,
+        ...this.createdByFields(topic)
+      }), state.datastore.autoRules ?? [])],
+      nextTopicNumber: topicNumber + 1,
       generatedAt: new Date().toISOString()
     };
 
@@ -178,11 +202,7 @@ export class CacheService {
 
     const timestamp = new Date().toISOString();
     const updatedTopics = [...state.datastore.topics];
-    updatedTopics[topicIndex] = {
-      ...updatedTopics[topicIndex],
-      ...updates,
-      updatedAt: timestamp
-    };
+    updatedTopics[topicIndex] = this.mergeTopicUpdate(updatedTopics[topicIndex], updates, state.datastore, updatedTopics, timestamp);
 
     const updatedDatastore: Datastore = {
       ...state.datastore,
@@ -219,12 +239,14 @@ export class CacheService {
     const timestamp = new Date().toISOString();
     const updateMap = new Map(updates.map(u => [u.topicId, u.changes]));
     
-    const updatedTopics = state.datastore.topics.map(topic => {
+    // Updated sequentially so renamed topics see each other's new slugs
+    const datastore = state.datastore;
+    const updatedTopics = [...datastore.topics];
+    updatedTopics.forEach((topic, index) => {
       const changes = updateMap.get(topic.id);
       if (changes) {
-        return { ...topic, ...changes, updatedAt: timestamp };
+        updatedTopics[index] = this.mergeTopicUpdate(topic, changes, datastore, updatedTopics, timestamp);
       }
-      return topic;
     });
 
     const updatedDatastore: Datastore = {
@@ -237,6 +259,24 @@ export class CacheService {
     return { success: true, message: `${updates.length} Themen aktualisiert` };
   }
 
+  // ==================== WORKSPACE SETTINGS ====================
+
+  updateRaciDefaults(raciDefaults: RaciDefaults): CacheMutationResult {
+    return this.updateWorkspaceSetting('raciDefaults', raciDefaults, 'RACI-Vorgaben aktualisiert');
+  }
+
+  updateAutoRules(autoRules: AutoRule[]): CacheMutationResult {
+    return this.updateWorkspaceSetting('autoRules', autoRules, 'Regeln aktualisiert');
+  }
+
+  updateReferencePolicy(referencePolicy: ReferencePolicy): CacheMutationResult {
+    return this.updateWorkspaceSetting('referencePolicy', referencePolicy, 'Referenz-Vorgaben aktualisiert');
+  }
+
+  updateSearchStopWords(searchStopWords: SearchStopWords): CacheMutationResult {
+    return this.updateWorkspaceSetting('searchStopWords', searchStopWords, 'Stoppwörter aktualisiert');
+  }
+
   // ==================== MEMBER OPERATIONS ====================
 
   addMember(member: TeamMember): CacheMutationResult {
@@ -247,7 +287,7 @@ export class CacheService {
 
     const updatedDatastore: Datastore = {
       ...state.datastore,
-      members: [...state.datastore.members, member],
+      members: [...state.datastore.members, { ...member, ...this.createdByFields(member) }],
       generatedAt: new Date().toISOString()
     };
 
@@ -271,6 +311,7 @@ export class CacheService {
     updatedMembers[memberIndex] = {
       ...updatedMembers[memberIndex],
       ...updates,
+      ...this.updatedByFields(),
       updatedAt: timestamp
     };
 
@@ -338,9 +379,11 @@ export class CacheService {
       modifiedAt: timestamp
     };
 
-    // If tag name changed, update references in topics
+    // If tag name changed, update references in topics and rule actions
     let updatedTopics = state.datastore.topics;
+    let updatedRules = state.datastore.autoRules;
     if (updates.name && updates.name !== oldTag.name) {
+      updatedRules = updatedRules && replaceRuleTag(updatedRules, oldTag.name, updates.name);
       updatedTopics = state.datastore.topics.map(topic => {
         if (topic.tags && topic.tags.includes(oldTag.name)) {
           return {
@@ -356,6 +399,7 @@ export class CacheService {
       ...state.datastore,
       tags: updatedTags,
       topics: updatedTopics,
+      autoRules: updatedRules,
       generatedAt: timestamp
     };
 
@@ -374,7 +418,7 @@ export class CacheService {
       return { success: false, message: 'Tag nicht gefunden' };
     }
 
-    // Remove tag references from topics
+    // Remove tag references from topics and rule actions
     const updatedTopics = state.datastore.topics.map(topic => {
       if (topic.tags && topic.tags.includes(tagToDelete.name)) {
         return {
@@ -389,6 +433,7 @@ export class CacheService {
       ...state.datastore,
       tags: state.datastore.tags.filter(t => t.id !== tagId),
       topics: updatedTopics,
+      autoRules: state.datastore.autoRules && replaceRuleTag(state.datastore.autoRules, tagToDelete.name, null),
       generatedAt: new Date().toISOString()
     };
 
@@ -401,15 +446,17 @@ export class CacheService {
   /**
    * Mark cache as synced (no dirty changes).
    * Called after successful backend save.
+   * The instance id is taken over if the save assigned one to an older datastore.
    */
-  markAsSynced(newRevisionId: number): void {
+  markAsSynced(newRevisionId: number, instanceId?: string): void {
     const state = this.cacheStateSubject.value;
     if (state.datastore) {
       this.cacheStateSubject.next({
         ...state,
         datastore: {
           ...state.datastore,
-          revisionId: newRevisionId
+          revisionId: newRevisionId,
+          instanceId: instanceId ?? state.datastore.instanceId
         },
         isDirty: false,
         lastSyncTime: new Date().toISOString(),
@@ -458,11 +505,17 @@ export class CacheService {
     if (!conflict) return;
 
     if (resolution === 'use-cache') {
-      // Keep cache version, mark as dirty to force re-save
+      // Keep cache version, mark as dirty to force re-save. The save is based on the
+      // backend version now, so it deliberately overwrites it.
       const state = this.cacheStateSubject.value;
+      const backendVersion = conflict.backendVersion;
       this.cacheStateSubject.next({
         ...state,
-        isDirty: true
+        datastore: state.datastore
+          ? { ...state.datastore, instanceId: backendVersion.instanceId, revisionId: backendVersion.revisionId }
+          : state.datastore,
+        isDirty: true,
+        revisionId: backendVersion.revisionId
       });
     } else if (resolution === 'use-backend') {
       // Accept backend version, discard local changes
@@ -500,10 +553,64 @@ export class CacheService {
     this.pendingChangesCount.set(changeCount);
   }
 
+  /**
+   * Replaces a datastore-level setting (e.g. RACI defaults or automatic rules).
+   */
+  private updateWorkspaceSetting<K extends keyof Datastore>(key: K, value: Datastore[K], message: string): CacheMutationResult {
+    const state = this.cacheStateSubject.value;
+    if (!state.datastore) {
+      return { success: false, message: 'Cache nicht initialisiert' };
+    }
+
+    const updatedDatastore: Datastore = {
+      ...state.datastore,
+      [key]: value,
+      generatedAt: new Date().toISOString()
+    };
+
+    this.updateCacheState(updatedDatastore);
+    return { success: true, message };
+  }
+
   private deepClone<T>(obj: T): T {
     return JSON.parse(JSON.stringify(obj));
   }
 
+  /**
+   * Applies updates to a topic: the key stays immutable, the description is sanitized,
+   * automatic rules run again if a field used in rule conditions changed, size changes
+   * are recorded and the slug follows the header.
+   */
+  private mergeTopicUpdate(topic: Topic, updates: Partial<Topic>, datastore: Datastore, topics: Topic[], timestamp: string): Topic {
+    let updated = normalizeTopicDescription({ ...topic, ...updates, key: topic.key, ...this.updatedByFields(), updatedAt: timestamp });
+    if (hasRuleInputChanged(topic, updated)) {
+      updated = applyAutoRules(updated, datastore.autoRules ?? []);
+    }
+    updated = recordSizeChange(topic, updated, timestamp, this.currentMemberId || undefined);
+    return updateTopicSlug(updated, topics);
+  }
+
+  /**
+   * Author fields for a new entity. An explicitly set createdBy is kept.
+   * Returns no fields if no current user is set.
+   */
+  private createdByFields(entity: { createdBy?: string }): { createdBy?: string; updatedBy?: string } {
+    if (!this.currentMemberId) {
+      return {};
+    }
+    return {
+      createdBy: entity.createdBy || this.currentMemberId,
+      updatedBy: this.currentMemberId
+    };
+  }
+
+  /**
+   * Author field for an updated entity. Returns no fields if no current user is set.
+   */
+  private updatedByFields(): { updatedBy?: string } {
+    return this.currentMemberId ? { updatedBy: this.currentMemberId } : {};
+  }
+
   /**
    * Generate a UUID for new entities.
    */
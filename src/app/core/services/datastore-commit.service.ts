@@ -5,12 +5,24 @@ import { LockService, LockAcquireResult } from './lock.service';
 import { RefreshService } from './refresh.service';
 import { Datastore, Topic, TeamMember, Tag, LockPurpose } from '../models';
 import { runPlausibilityChecks, PlausibilityResult } from './datastore-plausibility';
+import { replaceRuleTag } from '../../shared/utils/auto-rules.utils';
 
 export interface CommitResult {
   success: boolean;
   germanMessage: string;
   datastore?: Datastore;
   plausibilityResult?: PlausibilityResult;
+  /** True if the commit was rejected because datastore.json no longer matches the expected base */
+  conflict?: boolean;
+}
+
+/**
+ * Datastore version a commit is based on. Used when the change replaces the
+ * whole datastore instead of being applied to the current file content.
+ */
+export interface CommitBase {
+  instanceId?: string;
+  revisionId: number;
 }
 
 export interface ValidationError {
@@ -149,10 +161,14 @@ export class DatastoreCommitService {
    * Commit changes to the datastore.
    * Follows the specification: acquire lock, re-read, validate, apply, run plausibility checks,
    * update metadata, write, verify, write refresh, release lock.
+   *
+   * @param expectedBase - If set, the commit is rejected as a conflict unless datastore.json
+   *   still has this instance and revision (i.e. it was neither changed nor replaced since)
    */
   async commitChanges(
     modifyFn: (datastore: Datastore) => Datastore,
-    purpose: LockPurpose
+    purpose: LockPurpose,
+    expectedBase?: CommitBase
   ): Promise<CommitResult> {
     // Step 1: Acquire lock
     const lockResult = await this.lockService.acquireLock(purpose);
@@ -187,6 +203,19 @@ export class DatastoreCommitService {
         };
       }
 
+      // Step 3b: Reject changes based on a different instance or revision
+      if (expectedBase) {
+        const conflictMessage = this.checkExpectedBase(datastore, expectedBase);
+        if (conflictMessage) {
+          return {
+            success: false,
+            conflict: true,
+            germanMessage: conflictMessage,
+            datastore
+          };
+        }
+      }
+
       // Step 4: Apply the change (pure function)
       let modifiedDatastore = modifyFn(datastore);
 
@@ -198,6 +227,8 @@ export class DatastoreCommitService {
       // Step 6: Update metadata
       modifiedDatastore.revisionId = datastore.revisionId + 1;
       modifiedDatastore.generatedAt = new Date().toISOString();
+      // Keep the instance of the datastore on disk; older datastores get one on their first commit
+      modifiedDatastore.instanceId = datastore.instanceId ?? this.generateUUID();
 
       // Step 7: Write datastore.json (backup is created automatically)
       const newContent = JSON.stringify(modifiedDatastore, null, 2);
@@ -361,8 +392,11 @@ export class DatastoreCommitService {
           };
           const newName = datastore.tags[index].name;
           
-          // Update tag references in topics if name changed
+          // Update tag references in topics and rule actions if name changed
           if (oldName !== newName) {
+            if (datastore.autoRules) {
+              datastore.autoRules = replaceRuleTag(datastore.autoRules, oldName, newName);
+            }
             datastore.topics = datastore.topics.map(topic => {
               if (topic.tags && topic.tags.includes(oldName)) {
                 return {
@@ -398,6 +432,9 @@ export class DatastoreCommitService {
             }
             return topic;
           });
+          if (datastore.autoRules) {
+            datastore.autoRules = replaceRuleTag(datastore.autoRules, tagToDelete.name, null);
+          }
           // Remove the tag itself
           datastore.tags = datastore.tags.filter(t => t.id !== tagId);
         }
@@ -446,6 +483,22 @@ export class DatastoreCommitService {
 
   // Private helper methods
 
+  /**
+   * Returns a German conflict message if the datastore on disk is not the expected base, otherwise null.
+   * Datastores written before instance ids existed are only compared by revision.
+   */
+  private checkExpectedBase(datastore: Datastore, expectedBase: CommitBase): string | null {
+    if (expectedBase.instanceId !== undefined && datastore.instanceId !== expectedBase.instanceId) {
+      return 'Der Datenspeicher wurde ersetzt (z.B. aus einem Backup wiederhergestellt). ' +
+        'Ihre Änderungen wurden nicht gespeichert. Bitte verwerfen Sie die Änderungen und laden Sie neu.';
+    }
+    if (datastore.revisionId !== expectedBase.revisionId) {
+      return `Der Datenspeicher wurde zwischenzeitlich geändert (Revision ${datastore.revisionId}, erwartet: ${expectedBase.revisionId}). ` +
+        'Ihre Änderungen wurden nicht gespeichert.';
+    }
+    return null;
+  }
+
   private updateState(datastore: Datastore | null, isValid: boolean, errorMessage: string | null): void {
     const currentState = this.datastoreStateSubject.value;
     this.datastoreStateSubject.next({
@@ -462,6 +515,7 @@ export class DatastoreCommitService {
       schemaVersion: 1,
       generatedAt: new Date().toISOString(),
       revisionId: 0,
+      instanceId: this.generateUUID(),
 
       members: [
         {
@@ -504,6 +558,11 @@ export class DatastoreCommitService {
       errors.push({ field: 'revisionId', germanMessage: 'revisionId muss eine Zahl sein' });
     }
 
+    // Validate instanceId (optional)
+    if (data.instanceId !== undefined && typeof data.instanceId !== 'string') {
+      errors.push({ field: 'instanceId', germanMessage: 'instanceId muss eine Zeichenkette sein' });
+    }
+
     // Validate members array
     if (!Array.isArray(data.members)) {
       errors.push({ field: 'members', germanMessage: 'members muss ein Array sein' });
@@ -590,6 +649,9 @@ export class DatastoreCommitService {
       if (!Array.isArray(topic.raci.iMemberIds)) {
         errors.push({ field: `${prefix}.raci.iMemberIds`, germanMessage: `${prefix}.raci.iMemberIds muss ein Array sein` });
       }
+      if (topic.raci.escalationMemberIds !== undefined && !Array.isArray(topic.raci.escalationMemberIds)) {
+        errors.push({ field: `${prefix}.raci.escalationMemberIds`, germanMessage: `${prefix}.raci.escalationMemberIds muss ein Array sein` });
+      }
     }
 
     return errors;
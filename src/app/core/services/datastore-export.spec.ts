@@ -0,0 +1,109 @@
+import { describe, it, expect } from 'vitest';
+import { createPartialExport } from './datastore-export';
+import { runPlausibilityChecks } from './datastore-plausibility';
+import { Datastore, Topic, TeamMember, Tag } from '../models';
+
+const createMember = (id: string): TeamMember => ({
+  id,
+  displayName: `Member ${id}`,
+  active: true,
+  updatedAt: '2024-01-01T00:00:00.000Z',
+});
+
+const createTag = (id: string, name: string): Tag => ({
+  id,
+  name,
+  createdAt: '2024-01-01T00:00:00.000Z',
+  modifiedAt: '2024-01-01T00:00:00.000Z',
+  createdBy: 'm1',
+});
+
+const createTopic = (id: string, tags: string[], overrides?: Partial<Topic>): Topic => ({
+  id,
+  header: `Topic ${id}`,
+  tags,
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  ...overrides,
+});
+
+const createDatastore = (): Datastore => ({
+  schemaVersion: 1,
+  generatedAt: '2024-01-01T00:00:00.000Z',
+  revisionId: 42,
+  instanceId: 'instance-1',
+  members: [createMember('m1'), createMember('m2'), createMember('m3'), createMember('m4')],
+  tags: [createTag('tag-hr', 'HR'), createTag('tag-it', 'IT'), createTag('tag-fin', 'Finance')],
+  topics: [
+    createTopic('t1', ['HR'], {
+      raci: { r1MemberId: 'm1', r2MemberId: 'm2', cMemberIds: [], iMemberIds: [] },
+      connections: [
+        { targetTopicId: 't2', type: 'relatedTo' },
+        { targetTopicId: 't3', type: 'dependsOn' },
+      ],
+    }),
+    createTopic('t2', ['HR', 'IT'], {
+      raci: { r1MemberId: 'm1', cMemberIds: ['m3'], iMemberIds: [] },
+    }),
+    createTopic('t3', ['Finance'], {
+      raci: { r1MemberId: 'm4', cMemberIds: [], iMemberIds: [] },
+    }),
+  ],
+});
+
+describe('createPartialExport', () => {
+  it('should export only topics with one of the selected tags', () => {
+    const result = createPartialExport(createDatastore(), { tagNames: ['HR'], includeMembers: 'referenced' });
+
+    expect(result.topics.map((t) => t.id)).toEqual(['t1', 't2']);
+  });
+
+  it('should include all tags referenced by exported topics', () => {
+    const result = createPartialExport(createDatastore(), { tagNames: ['HR'], includeMembers: 'referenced' });
+
+    expect(result.tags!.map((t) => t.name)).toEqual(['HR', 'IT']);
+  });
+
+  it('should include only referenced members', () => {
+    const result = createPartialExport(createDatastore(), { tagNames: ['HR'], includeMembers: 'referenced' });
+
+    expect(result.members.map((m) => m.id)).toEqual(['m1', 'm2', 'm3']);
+  });
+
+  it('should include all members if requested', () => {
+    const result = createPartialExport(createDatastore(), { tagNames: ['HR'], includeMembers: 'all' });
+
+    expect(result.members).toHaveLength(4);
+  });
+
+  it('should remove connections to topics outside the export', () => {
+    const result = createPartialExport(createDatastore(), { tagNames: ['HR'], includeMembers: 'referenced' });
+
+    expect(result.topics[0].connections).toEqual([{ targetTopicId: 't2', type: 'relatedTo' }]);
+  });
+
+  it('should export everything if no tags are selected', () => {
+    const datastore = createDatastore();
+
+    const result = createPartialExport(datastore, { tagNames: [], includeMembers: 'referenced' });
+
+    expect(result.topics).toEqual(datastore.topics);
+    expect(result.tags).toEqual(datastore.tags);
+  });
+
+  it('should start a new history', () => {
+    const result = createPartialExport(createDatastore(), { tagNames: ['HR'], includeMembers: 'referenced' });
+
+    expect(result.revisionId).toBe(0);
+    expect(result.instanceId).toBeUndefined();
+  });
+
+  it('should produce a self-consistent datastore', () => {
+    const result = createPartialExport(createDatastore(), { tagNames: ['IT'], includeMembers: 'referenced' });
+
+    const { result: plausibility } = runPlausibilityChecks(result);
+
+    expect(plausibility.hasChanges).toBe(false);
+  });
+});
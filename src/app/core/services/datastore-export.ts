@@ -0,0 +1,84 @@
+import { Datastore, Topic } from '../models';
+
+/**
+ * Which members are included in a partial export:
+ * - referenced: only members referenced in the RACI of exported topics
+ * - all: all members of the datastore
+ */
+export type ExportMemberScope = 'referenced' | 'all';
+
+/**
+ * Options for a partial datastore export.
+ */
+export interface PartialExportOptions {
+  /** Export only topics with at least one of these tags (empty = all topics) */
+  tagNames: string[];
+  /** Members to include */
+  includeMembers: ExportMemberScope;
+}
+
+/**
+ * Collects all member IDs referenced by a topic (RACI, delegation and rotation).
+ */
+function collectReferencedMemberIds(topic: Topic): string[] {
+  const { r1MemberId, r2MemberId, r3MemberId, cMemberIds, iMemberIds, escalationMemberIds } = topic.raci;
+  return [
+    r1MemberId,
+    r2MemberId,
+    r3MemberId,
+    ...cMemberIds,
+    ...iMemberIds,
+    ...(escalationMemberIds || []),
+    topic.delegation?.memberId,
+    ...(topic.rotation?.memberIds || []),
+  ].filter(
+    (id): id is string => !!id
+  );
+}
+
+/**
+ * Create a self-consistent subset of the datastore, suitable for importing into
+ * another data folder.
+ * - Topics: all topics with at least one of the selected tags
+ * - Tags: all managed tags referenced by the exported topics
+ * - Members: members referenced by the exported topics (or all members)
+ * - Connections to topics outside the export are removed
+ *
+ * The export starts a new history: revisionId is 0 and no instanceId is set.
+ * @param datastore The datastore to export from
+ * @param options Selection options
+ * @returns The exported datastore subset
+ */
+export function createPartialExport(datastore: Datastore, options: PartialExportOptions): Datastore {
+  const selectedTags = new Set(options.tagNames);
+
+  const topics = selectedTags.size === 0
+    ? datastore.topics
+    : datastore.topics.filter((topic) => (topic.tags || []).some((tagName) => selectedTags.has(tagName)));
+
+  const exportedTopicIds = new Set(topics.map((t) => t.id));
+  const exportedTopics = topics.map((topic) => {
+    if (!topic.connections || topic.connections.length === 0) {
+      return topic;
+    }
+    const connections = topic.connections.filter((c) => exportedTopicIds.has(c.targetTopicId));
+    return connections.length === topic.connections.length ? topic : { ...topic, connections };
+  });
+
+  const referencedTagNames = new Set(exportedTopics.flatMap((topic) => topic.tags || []));
+  const tags = (datastore.tags || []).filter((tag) => referencedTagNames.has(tag.name));
+
+  const referencedMemberIds = new Set(exportedTopics.flatMap(collectReferencedMemberIds));
+  const members = options.includeMembers === 'all'
+    ? datastore.members
+    : datastore.members.filter((member) => referencedMemberIds.has(member.id));
+
+  return {
+    schemaVersion: datastore.schemaVersion,
+    generatedAt: new Date().toISOString(),
+    revisionId: 0,
+    members,
+    topics: exportedTopics,
+    tags,
+  };
+}
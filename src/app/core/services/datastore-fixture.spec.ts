@@ -0,0 +1,98 @@
+import { describe, it, expect, beforeEach } from 'vitest';
+import fixture from '../../../../test_data/datastore.json';
+import { canonicalStringify, computeDatastoreChecksum } from './checksum';
+import { runPlausibilityChecks } from './datastore-plausibility';
+import { SearchEngineService } from './search-engine.service';
+import { Datastore } from '../models';
+
+/**
+ * Contract tests against the recorded datastore in test_data/datastore.json.
+ *
+ * The fixture was written by an earlier release and contains legacy fields
+ * (e.g. r1Name, tagsString) and tag names without a managed tag. Datastores
+ * like this exist in shared folders, so the current client must keep reading
+ * them without losing data or changing the file format.
+ */
+
+// Mock localStorage
+const localStorageMock = (() => {
+  let store: Record<string, string> = {};
+  return {
+    getItem: (key: string) => store[key] || null,
+    setItem: (key: string, value: string) => { store[key] = value; },
+    removeItem: (key: string) => { delete store[key]; },
+    clear: () => { store = {}; }
+  };
+})();
+Object.defineProperty(globalThis, 'localStorage', { value: localStorageMock });
+
+const loadFixture = (): Datastore => structuredClone(fixture) as unknown as Datastore;
+
+/** Collects all object keys (recursively) */
+function collectKeys(value: unknown, keys = new Set<string>()): Set<string> {
+  if (Array.isArray(value)) {
+    value.forEach(item => collectKeys(item, keys));
+  } else if (value !== null && typeof value === 'object') {
+    for (const [key, child] of Object.entries(value)) {
+      keys.add(key);
+      collectKeys(child, keys);
+    }
+  }
+  return keys;
+}
+
+describe('Recorded datastore fixture', () => {
+  beforeEach(() => {
+    localStorageMock.clear();
+  });
+
+  it('should use camelCase keys and omit missing optional fields instead of null', () => {
+    const datastore = loadFixture();
+
+    for (const key of collectKeys(datastore)) {
+      expect(key, `key "${key}"`).toMatch(/^[a-z][a-zA-Z0-9]*$/);
+    }
+    expect(JSON.stringify(datastore)).not.toContain(':null');
+  });
+
+  it('should keep unknown legacy fields through the write format', () => {
+    const datastore = loadFixture();
+
+    const written = JSON.parse(JSON.stringify(datastore, null, 2)) as Datastore;
+
+    expect(written).toEqual(datastore);
+    expect(canonicalStringify(written)).toBe(canonicalStringify(datastore));
+    expect(collectKeys(written)).toEqual(collectKeys(datastore));
+  });
+
+  it('should have a stable checksum', async () => {
+    expect(await computeDatastoreChecksum(loadFixture())).toBe(await computeDatastoreChecksum(loadFixture()));
+  });
+
+  it('should only clean up references during plausibility checks', () => {
+    const datastore = loadFixture();
+
+    const { datastore: cleaned, result } = runPlausibilityChecks(datastore);
+
+    // Tag names without a managed tag are removed, nothing else changes
+    expect(result.removedTagReferences).toBeGreaterThan(0);
+    expect(result.removedMemberReferences).toBe(0);
+    expect(result.correctedTopicFields).toBe(0);
+    expect(result.normalizedTimestamps).toBe(0);
+    expect(cleaned.members).toEqual(datastore.members);
+    expect(cleaned.tags).toEqual(datastore.tags);
+    expect(cleaned.topics.map(t => t.id)).toEqual(datastore.topics.map(t => t.id));
+    expect(collectKeys(cleaned)).toEqual(collectKeys(datastore));
+  });
+
+  it('should be searchable', async () => {
+    const datastore = loadFixture();
+    const service = new SearchEngineService();
+
+    await service.buildIndex(datastore);
+
+    expect(service.getIndexSize()).toBe(datastore.topics.length);
+    const firstHeader = datastore.topics[0].header.split(/\s+/)[0];
+    expect(service.search(firstHeader).some(hit => hit.entityId === datastore.topics[0].id)).toBe(true);
+  });
+});
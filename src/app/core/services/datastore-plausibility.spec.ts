@@ -6,6 +6,11 @@ import {
   validateMemberColors,
   validateTagColors,
   removeInvalidTopicConnections,
+  normalizeTimestamps,
+  removeInvalidAbsences,
+  removeInvalidDelegations,
+  cleanTopicRotations,
+  removeInvalidRuleTags,
   runPlausibilityChecks,
 } from './datastore-plausibility';
 import { Datastore, Topic, TeamMember, Tag, TShirtSize, TopicConnectionType } from '../models';
@@ -279,6 +284,34 @@ describe('removeInvalidMemberReferences', () => {
     expect(result.datastore.topics[0].raci.cMemberIds).toEqual([]);
     expect(result.datastore.topics[0].raci.iMemberIds).toEqual([]);
   });
+
+  it('should remove invalid escalation member references and keep the order', () => {
+    const datastore = createDatastore({
+      members: [createMember('member-1', 'User 1'), createMember('member-2', 'User 2'), createMember('member-3', 'User 3')],
+      topics: [
+        createTopic('topic-1', 'Topic 1', 'member-1', {
+          raci: { r1MemberId: 'member-1', cMemberIds: [], iMemberIds: [], escalationMemberIds: ['member-3', 'gone', 'member-2'] },
+        }),
+      ],
+    });
+
+    const result = removeInvalidMemberReferences(datastore);
+
+    expect(result.removedCount).toBe(1);
+    expect(result.datastore.topics[0].raci.escalationMemberIds).toEqual(['member-3', 'member-2']);
+  });
+
+  it('should remove unknown members from RACI defaults', () => {
+    const datastore = createDatastore({
+      members: [createMember('member-1', 'User 1')],
+      raciDefaults: { cMemberIds: ['member-1', 'gone'], iMemberIds: ['gone-too'] },
+    });
+
+    const result = removeInvalidMemberReferences(datastore);
+
+    expect(result.removedCount).toBe(2);
+    expect(result.datastore.raciDefaults).toEqual({ cMemberIds: ['member-1'], iMemberIds: [] });
+  });
 });
 
 describe('runPlausibilityChecks', () => {
@@ -843,3 +876,234 @@ describe('removeInvalidTopicConnections', () => {
     expect(result.datastore.topics[0].connections).toEqual([]);
   });
 });
+
+describe('normalizeTimestamps', () => {
+  it('should not modify canonical timestamps', () => {
+    const datastore = createDatastore({
+      members: [createMember('member-1', 'User 1')],
+      topics: [createTopic('topic-1', 'Topic 1', 'member-1')],
+      tags: [createTag('tag-1', 'tag')],
+    });
+
+    const result = normalizeTimestamps(datastore);
+
+    expect(result.correctedCount).toBe(0);
+    expect(result.changeLog).toHaveLength(0);
+    expect(result.datastore.topics[0]).toBe(datastore.topics[0]);
+  });
+
+  it('should normalize topic, member and tag timestamps', () => {
+    const datastore = createDatastore({
+      members: [{ ...createMember('member-1', 'User 1'), updatedAt: '2024-05-01 08:30:00' }],
+      topics: [createTopic('topic-1', 'Topic 1', 'member-1', { updatedAt: '2024-05-01T10:30:00+02:00' })],
+      tags: [{ ...createTag('tag-1', 'tag'), createdAt: '2024-05-01T08:30:00Z', modifiedAt: '2024-05-01T08:30:00Z' }],
+    });
+
+    const result = normalizeTimestamps(datastore);
+
+    expect(result.correctedCount).toBe(4);
+    expect(result.datastore.topics[0].updatedAt).toBe('2024-05-01T08:30:00.000Z');
+    expect(result.datastore.members[0].updatedAt).toBe('2024-05-01T08:30:00.000Z');
+    expect(result.datastore.tags![0].createdAt).toBe('2024-05-01T08:30:00.000Z');
+    expect(result.datastore.tags![0].modifiedAt).toBe('2024-05-01T08:30:00.000Z');
+    expect(result.changeLog).toHaveLength(3);
+  });
+
+  it('should normalize size history timestamps', () => {
+    const datastore = createDatastore({
+      topics: [createTopic('topic-1', 'Topic 1', 'member-1', {
+        sizeHistory: [
+          { to: 'S', changedAt: '2024-04-01T08:30:00.000Z' },
+          { from: 'S', to: 'M', changedAt: '2024-05-01 08:30:00' },
+        ],
+      })],
+    });
+
+    const result = normalizeTimestamps(datastore);
+
+    expect(result.correctedCount).toBe(1);
+    expect(result.datastore.topics[0].sizeHistory![0]).toBe(datastore.topics[0].sizeHistory![0]);
+    expect(result.datastore.topics[0].sizeHistory![1].changedAt).toBe('2024-05-01T08:30:00.000Z');
+    expect(result.changeLog).toEqual([
+      'Topic "Topic 1" (topic-1) size change 2: changedAt normalized from "2024-05-01 08:30:00" to "2024-05-01T08:30:00.000Z"',
+    ]);
+  });
+
+  it('should log but keep unparseable timestamps', () => {
+    const datastore = createDatastore({
+      topics: [createTopic('topic-1', 'Topic 1', 'member-1', { updatedAt: 'gestern' })],
+    });
+
+    const result = normalizeTimestamps(datastore);
+
+    expect(result.correctedCount).toBe(0);
+    expect(result.datastore.topics[0].updatedAt).toBe('gestern');
+    expect(result.changeLog).toHaveLength(1);
+  });
+
+  it('should handle undefined tags', () => {
+    const datastore = createDatastore({ tags: undefined });
+
+    const result = normalizeTimestamps(datastore);
+
+    expect(result.correctedCount).toBe(0);
+    expect(result.datastore.tags).toBeUndefined();
+  });
+});
+
+describe('removeInvalidAbsences', () => {
+  it('should keep valid absences', () => {
+    const datastore = createDatastore({
+      members: [{ ...createMember('member-1', 'User 1'), absences: [{ from: '2024-06-01', to: '2024-06-14', type: 'vacation' }] }],
+    });
+
+    const result = removeInvalidAbsences(datastore);
+
+    expect(result.removedCount).toBe(0);
+    expect(result.datastore.members[0]).toBe(datastore.members[0]);
+  });
+
+  it('should remove absences with invalid dates or reversed ranges', () => {
+    const datastore = createDatastore({
+      members: [{
+        ...createMember('member-1', 'User 1'),
+        absences: [
+          { from: '2024-06-01', to: '2024-06-14', type: 'vacation' },
+          { from: '2024-06-14', to: '2024-06-01', type: 'vacation' },
+          { from: 'morgen', to: '2024-06-01', type: 'sick' },
+        ],
+      }],
+    });
+
+    const result = removeInvalidAbsences(datastore);
+
+    expect(result.removedCount).toBe(2);
+    expect(result.datastore.members[0].absences).toEqual([{ from: '2024-06-01', to: '2024-06-14', type: 'vacation' }]);
+    expect(result.changeLog).toHaveLength(1);
+  });
+
+  it('should handle members without absences', () => {
+    const datastore = createDatastore({ members: [createMember('member-1', 'User 1')] });
+
+    const result = removeInvalidAbsences(datastore);
+
+    expect(result.removedCount).toBe(0);
+    expect(result.datastore.members[0].absences).toBeUndefined();
+  });
+});
+
+describe('removeInvalidDelegations', () => {
+  const now = new Date(2024, 5, 5, 12);
+  const members = [createMember('member-1', 'User 1'), createMember('member-2', 'User 2')];
+
+  it('should keep current and future delegations', () => {
+    const datastore = createDatastore({
+      members,
+      topics: [
+        createTopic('topic-1', 'Topic 1', 'member-1', { delegation: { memberId: 'member-2', from: '2024-06-01', to: '2024-06-05' } }),
+        createTopic('topic-2', 'Topic 2', 'member-1', { delegation: { memberId: 'member-2', from: '2024-07-01', to: '2024-07-14' } }),
+      ],
+    });
+
+    const result = removeInvalidDelegations(datastore, now);
+
+    expect(result.removedCount).toBe(0);
+    expect(result.datastore.topics).toEqual(datastore.topics);
+  });
+
+  it('should remove expired delegations', () => {
+    const datastore = createDatastore({
+      members,
+      topics: [createTopic('topic-1', 'Topic 1', 'member-1', { delegation: { memberId: 'member-2', from: '2024-05-01', to: '2024-06-04' } })],
+    });
+
+    const result = removeInvalidDelegations(datastore, now);
+
+    expect(result.removedCount).toBe(1);
+    expect(result.datastore.topics[0].delegation).toBeUndefined();
+    expect(result.changeLog[0]).toContain('expired');
+  });
+
+  it('should remove delegations to unknown members or with invalid dates', () => {
+    const datastore = createDatastore({
+      members,
+      topics: [
+        createTopic('topic-1', 'Topic 1', 'member-1', { delegation: { memberId: 'gone', from: '2024-06-01', to: '2024-06-14' } }),
+        createTopic('topic-2', 'Topic 2', 'member-1', { delegation: { memberId: 'member-2', from: '2024-06-14', to: '2024-06-01' } }),
+      ],
+    });
+
+    const result = removeInvalidDelegations(datastore, now);
+
+    expect(result.removedCount).toBe(2);
+    expect(result.datastore.topics.every(t => t.delegation === undefined)).toBe(true);
+  });
+});
+
+describe('cleanTopicRotations', () => {
+  const members = [createMember('member-1', 'User 1'), createMember('member-2', 'User 2')];
+
+  it('should keep valid rotations', () => {
+    const datastore = createDatastore({
+      members,
+      topics: [createTopic('topic-1', 'Topic 1', 'member-1', { rotation: { memberIds: ['member-1', 'member-2'], period: 'weekly', startDate: '2024-06-03' } })],
+    });
+
+    const result = cleanTopicRotations(datastore);
+
+    expect(result.correctedCount).toBe(0);
+    expect(result.datastore.topics[0]).toBe(datastore.topics[0]);
+  });
+
+  it('should remove unknown members from rotations', () => {
+    const datastore = createDatastore({
+      members,
+      topics: [createTopic('topic-1', 'Topic 1', 'member-1', { rotation: { memberIds: ['member-2', 'gone', 'member-1'], period: 'monthly', startDate: '2024-06-03' } })],
+    });
+
+    const result = cleanTopicRotations(datastore);
+
+    expect(result.correctedCount).toBe(1);
+    expect(result.datastore.topics[0].rotation?.memberIds).toEqual(['member-2', 'member-1']);
+  });
+
+  it('should remove rotations without members or with an invalid start date', () => {
+    const datastore = createDatastore({
+      members,
+      topics: [
+        createTopic('topic-1', 'Topic 1', 'member-1', { rotation: { memberIds: ['gone'], period: 'weekly', startDate: '2024-06-03' } }),
+        createTopic('topic-2', 'Topic 2', 'member-1', { rotation: { memberIds: ['member-1'], period: 'weekly', startDate: 'montags' } }),
+      ],
+    });
+
+    const result = cleanTopicRotations(datastore);
+
+    expect(result.correctedCount).toBe(2);
+    expect(result.datastore.topics.every(t => t.rotation === undefined)).toBe(true);
+  });
+});
+
+describe('removeInvalidRuleTags', () => {
+  const rule = { id: 'rule-1', name: 'Netzwerk', field: 'header' as const, contains: 'firewall', enabled: true };
+
+  it('should remove unknown tags from rule actions', () => {
+    const datastore = createDatastore({
+      tags: [createTag('tag-1', 'Netzwerk')],
+      autoRules: [{ ...rule, addTags: ['Netzwerk', 'Gelöscht'] }],
+    });
+
+    const result = removeInvalidRuleTags(datastore);
+
+    expect(result.removedCount).toBe(1);
+    expect(result.datastore.autoRules?.[0].addTags).toEqual(['Netzwerk']);
+  });
+
+  it('should keep rule tags if no managed tags exist', () => {
+    const datastore = createDatastore({ autoRules: [{ ...rule, addTags: ['Netzwerk'] }] });
+
+    const result = removeInvalidRuleTags(datastore);
+
+    expect(result.removedCount).toBe(0);
+    expect(result.datastore).toBe(datastore);
+  });
+});
@@ -1,5 +1,7 @@
 import { Datastore, Topic, TShirtSize, TopicConnectionType } from '../models';
-import { isValidHexColor, normalizeHexColor } from '../../shared/utils/validation.utils';
+import { isCanonicalTimestamp, isValidHexColor, normalizeHexColor, normalizeTimestamp } from '../../shared/utils/validation.utils';
+import { isValidAbsence } from '../../shared/utils/absence.utils';
+import { isDelegationExpired, isValidDelegation, isValidRotation } from '../../shared/utils/responsible.utils';
 
 const VALID_SIZES: TShirtSize[] = ['XXS', 'XS', 'S', 'M', 'L', 'XL', 'XXL'];
 const VALID_CONNECTION_TYPES: TopicConnectionType[] = ['dependsOn', 'blocks', 'relatedTo'];
@@ -22,6 +24,16 @@ export interface PlausibilityResult {
   correctedTagColors: number;
   /** Number of invalid topic connections removed */
   removedTopicConnections: number;
+  /** Number of timestamps normalized to RFC 3339 UTC */
+  normalizedTimestamps: number;
+  /** Number of invalid member absences removed */
+  removedAbsences: number;
+  /** Number of invalid or expired topic delegations removed */
+  removedDelegations: number;
+  /** Number of topic rotation corrections made */
+  correctedRotations: number;
+  /** Number of unknown tags removed from automatic rule actions */
+  removedRuleTags: number;
   /** Detailed log of changes for debugging */
   changeLog: string[];
 }
@@ -73,7 +85,8 @@ export function removeInvalidTagReferences(datastore: Datastore): {
 /**
  * Remove invalid member references from topics.
  * A member reference is invalid if the member ID doesn't exist in the members array.
- * This checks r1MemberId, r2MemberId, r3MemberId, cMemberIds, and iMemberIds.
+ * This checks r1MemberId, r2MemberId, r3MemberId, cMemberIds, iMemberIds and escalationMemberIds,
+ * as well as the workspace RACI defaults.
  * @param datastore The datastore to check
  * @returns Updated datastore with invalid member references removed
  */
@@ -138,6 +151,18 @@ export function removeInvalidMemberReferences(datastore: Datastore): {
       removedCount += invalidIMembers.length;
     }
 
+    // Check escalationMemberIds (optional)
+    const invalidEscalationMembers = (updatedRaci.escalationMemberIds || []).filter((id) => !validMemberIds.has(id));
+    if (invalidEscalationMembers.length > 0) {
+      changes.push(`removed escalationMemberIds [${invalidEscalationMembers.join(', ')}]`);
+      updatedRaci = {
+        ...updatedRaci,
+        escalationMemberIds: updatedRaci.escalationMemberIds!.filter((id) => validMemberIds.has(id)),
+      };
+      hasChanges = true;
+      removedCount += invalidEscalationMembers.length;
+    }
+
     if (changes.length > 0) {
       changeLog.push(`Topic "${topic.header}" (${topic.id}): ${changes.join('; ')}`);
     }
@@ -148,8 +173,22 @@ export function removeInvalidMemberReferences(datastore: Datastore): {
     return topic;
   });
 
+  // Check workspace RACI defaults
+  let raciDefaults = datastore.raciDefaults;
+  if (raciDefaults) {
+    const invalidDefaults = [...raciDefaults.cMemberIds, ...raciDefaults.iMemberIds].filter((id) => !validMemberIds.has(id));
+    if (invalidDefaults.length > 0) {
+      changeLog.push(`RACI defaults: removed member IDs [${invalidDefaults.join(', ')}]`);
+      raciDefaults = {
+        cMemberIds: raciDefaults.cMemberIds.filter((id) => validMemberIds.has(id)),
+        iMemberIds: raciDefaults.iMemberIds.filter((id) => validMemberIds.has(id)),
+      };
+      removedCount += invalidDefaults.length;
+    }
+  }
+
   return {
-    datastore: { ...datastore, topics: updatedTopics },
+    datastore: { ...datastore, topics: updatedTopics, ...(raciDefaults ? { raciDefaults } : {}) },
     removedCount,
     changeLog,
   };
@@ -383,6 +422,250 @@ export function removeInvalidTopicConnections(datastore: Datastore): {
   };
 }
 
+/**
+ * Normalize a single timestamp field of an entity.
+ * Returns the entity unchanged if the timestamp is already canonical or cannot be parsed.
+ */
+function normalizeTimestampField<T extends object>(
+  entity: T,
+  field: keyof T & string,
+  changes: string[]
+): T {
+  const value = entity[field] as unknown as string | undefined;
+  if (value === undefined || isCanonicalTimestamp(value)) {
+    return entity;
+  }
+  const normalized = normalizeTimestamp(value);
+  if (!normalized) {
+    // Required timestamps are kept as they are; a wrong replacement would be worse than a wrong sort position
+    changes.push(`${field} "${value}" is not a valid timestamp (keeping as is)`);
+    return entity;
+  }
+  changes.push(`${field} normalized from "${value}" to "${normalized}"`);
+  return { ...entity, [field]: normalized };
+}
+
+/**
+ * Normalize all entity timestamps to RFC 3339 UTC (as produced by Date.toISOString()).
+ * Older or externally edited datastores may contain other formats (missing milliseconds,
+ * time zone offsets, "YYYY-MM-DD HH:MM:SS"), which breaks sorting by date.
+ * Timestamps that cannot be parsed are logged but kept.
+ * @param datastore The datastore to check
+ * @returns Updated datastore with normalized timestamps
+ */
+export function normalizeTimestamps(datastore: Datastore): {
+  datastore: Datastore;
+  correctedCount: number;
+  changeLog: string[];
+} {
+  let correctedCount = 0;
+  const changeLog: string[] = [];
+
+  const normalizeEntity = <T extends object>(entity: T, fields: (keyof T & string)[], label: string): T => {
+    const changes: string[] = [];
+    let updated = entity;
+    for (const field of fields) {
+      const before = updated;
+      updated = normalizeTimestampField(updated, field, changes);
+      if (updated !== before) {
+        correctedCount++;
+      }
+    }
+    if (changes.length > 0) {
+      changeLog.push(`${label}: ${changes.join('; ')}`);
+    }
+    return updated;
+  };
+
+  const updatedTopics = datastore.topics.map((topic) => {
+    const label = `Topic "${topic.header}" (${topic.id})`;
+    const updated = normalizeEntity(topic, ['updatedAt'], label);
+    if (!topic.sizeHistory) {
+      return updated;
+    }
+    const sizeHistory = topic.sizeHistory.map((change, index) =>
+      normalizeEntity(change, ['changedAt'], `${label} size change ${index + 1}`)
+    );
+    return sizeHistory.every((change, index) => change === topic.sizeHistory![index])
+      ? updated
+      : { ...updated, sizeHistory };
+  });
+  const updatedMembers = datastore.members.map((member) =>
+    normalizeEntity(member, ['updatedAt'], `Member "${member.displayName}" (${member.id})`)
+  );
+  const updatedTags = datastore.tags?.map((tag) =>
+    normalizeEntity(tag, ['createdAt', 'modifiedAt'], `Tag "${tag.name}" (${tag.id})`)
+  );
+
+  return {
+    datastore: { ...datastore, topics: updatedTopics, members: updatedMembers, tags: updatedTags },
+    correctedCount,
+    changeLog,
+  };
+}
+
+/**
+ * Remove member absences with missing or invalid dates, an end before the start,
+ * or an unknown type.
+ * @param datastore The datastore to check
+ * @returns Updated datastore with invalid absences removed
+ */
+export function removeInvalidAbsences(datastore: Datastore): {
+  datastore: Datastore;
+  removedCount: number;
+  changeLog: string[];
+} {
+  let removedCount = 0;
+  const changeLog: string[] = [];
+
+  const updatedMembers = datastore.members.map((member) => {
+    if (!member.absences || member.absences.length === 0) {
+      return member;
+    }
+    const validAbsences = member.absences.filter((absence) => isValidAbsence(absence));
+    if (validAbsences.length === member.absences.length) {
+      return member;
+    }
+    const removed = member.absences.length - validAbsences.length;
+    removedCount += removed;
+    changeLog.push(`Member "${member.displayName}" (${member.id}): removed ${removed} invalid absence(s)`);
+    return { ...member, absences: validAbsences };
+  });
+
+  return {
+    datastore: { ...datastore, members: updatedMembers },
+    removedCount,
+    changeLog,
+  };
+}
+
+/**
+ * Remove topic delegations that are invalid (unknown delegate, invalid dates)
+ * or have ended, so expired delegations disappear with the next save.
+ * @param datastore The datastore to check
+ * @param now Point in time to evaluate expiry (default: current time)
+ * @returns Updated datastore with invalid and expired delegations removed
+ */
+export function removeInvalidDelegations(datastore: Datastore, now: Date = new Date()): {
+  datastore: Datastore;
+  removedCount: number;
+  changeLog: string[];
+} {
+  const validMemberIds = new Set(datastore.members.map((m) => m.id));
+  let removedCount = 0;
+  const changeLog: string[] = [];
+
+  const updatedTopics = datastore.topics.map((topic) => {
+    const delegation = topic.delegation;
+    if (!delegation) {
+      return topic;
+    }
+    let reason: string | null = null;
+    if (!isValidDelegation(delegation)) {
+      reason = 'invalid';
+    } else if (!validMemberIds.has(delegation.memberId)) {
+      reason = `unknown member "${delegation.memberId}"`;
+    } else if (isDelegationExpired(delegation, now)) {
+      reason = `expired on ${delegation.to}`;
+    }
+    if (!reason) {
+      return topic;
+    }
+    removedCount++;
+    changeLog.push(`Topic "${topic.header}" (${topic.id}): removed delegation (${reason})`);
+    return { ...topic, delegation: undefined };
+  });
+
+  return {
+    datastore: { ...datastore, topics: updatedTopics },
+    removedCount,
+    changeLog,
+  };
+}
+
+/**
+ * Remove unknown members from topic rotations and remove rotations that are
+ * left without members or have an invalid period or start date.
+ * @param datastore The datastore to check
+ * @returns Updated datastore with cleaned rotations
+ */
+export function cleanTopicRotations(datastore: Datastore): {
+  datastore: Datastore;
+  correctedCount: number;
+  changeLog: string[];
+} {
+  const validMemberIds = new Set(datastore.members.map((m) => m.id));
+  let correctedCount = 0;
+  const changeLog: string[] = [];
+
+  const updatedTopics = datastore.topics.map((topic) => {
+    const rotation = topic.rotation;
+    if (!rotation) {
+      return topic;
+    }
+    const memberIds = (rotation.memberIds || []).filter((id) => validMemberIds.has(id));
+    const cleaned = { ...rotation, memberIds };
+    if (!isValidRotation(cleaned)) {
+      correctedCount++;
+      changeLog.push(`Topic "${topic.header}" (${topic.id}): removed invalid rotation`);
+      return { ...topic, rotation: undefined };
+    }
+    if (memberIds.length !== (rotation.memberIds || []).length) {
+      correctedCount++;
+      changeLog.push(`Topic "${topic.header}" (${topic.id}): removed unknown members from rotation`);
+      return { ...topic, rotation: cleaned };
+    }
+    return topic;
+  });
+
+  return {
+    datastore: { ...datastore, topics: updatedTopics },
+    correctedCount,
+    changeLog,
+  };
+}
+
+/**
+ * Remove tags that don't exist in the managed tags list from automatic rule actions.
+ * Otherwise a rule keeps adding a deleted tag to every matching topic.
+ * @param datastore The datastore to check
+ * @returns Updated datastore with cleaned rules
+ */
+export function removeInvalidRuleTags(datastore: Datastore): {
+  datastore: Datastore;
+  removedCount: number;
+  changeLog: string[];
+} {
+  const validTagNames = new Set((datastore.tags || []).map((t) => t.name));
+  let removedCount = 0;
+  const changeLog: string[] = [];
+
+  // If no managed tags exist, keep all rule tags (same as for topic tags)
+  if (validTagNames.size === 0 || !datastore.autoRules) {
+    return { datastore, removedCount: 0, changeLog: [] };
+  }
+
+  const updatedRules = datastore.autoRules.map((rule) => {
+    const invalidTags = (rule.addTags || []).filter((tagName) => !validTagNames.has(tagName));
+    if (invalidTags.length === 0) {
+      return rule;
+    }
+    removedCount += invalidTags.length;
+    changeLog.push(`Rule "${rule.name}" (${rule.id}): removed invalid tags [${invalidTags.join(', ')}]`);
+    return { ...rule, addTags: (rule.addTags || []).filter((tagName) => validTagNames.has(tagName)) };
+  });
+
+  if (removedCount === 0) {
+    return { datastore, removedCount: 0, changeLog: [] };
+  }
+
+  return {
+    datastore: { ...datastore, autoRules: updatedRules },
+    removedCount,
+    changeLog,
+  };
+}
+
 /**
  * Run all plausibility checks on the datastore and return a cleaned version.
  * This function should be called before each save to ensure data consistency.
@@ -401,6 +684,11 @@ export function runPlausibilityChecks(datastore: Datastore): {
   let totalCorrectedMemberColors = 0;
   let totalCorrectedTagColors = 0;
   let totalRemovedConnections = 0;
+  let totalNormalizedTimestamps = 0;
+  let totalRemovedAbsences = 0;
+  let totalRemovedDelegations = 0;
+  let totalCorrectedRotations = 0;
+  let totalRemovedRuleTags = 0;
 
   // 1. Remove invalid tag references
   const tagResult = removeInvalidTagReferences(currentDatastore);
@@ -438,7 +726,37 @@ export function runPlausibilityChecks(datastore: Datastore): {
   totalRemovedConnections = connectionResult.removedCount;
   allChangeLogs.push(...connectionResult.changeLog);
 
-  const hasChanges = totalRemovedTags > 0 || totalRemovedMembers > 0 || totalCorrectedFields > 0 || totalCorrectedMemberColors > 0 || totalCorrectedTagColors > 0 || totalRemovedConnections > 0;
+  // 7. Normalize timestamps
+  const timestampResult = normalizeTimestamps(currentDatastore);
+  currentDatastore = timestampResult.datastore;
+  totalNormalizedTimestamps = timestampResult.correctedCount;
+  allChangeLogs.push(...timestampResult.changeLog);
+
+  // 8. Remove invalid member absences
+  const absenceResult = removeInvalidAbsences(currentDatastore);
+  currentDatastore = absenceResult.datastore;
+  totalRemovedAbsences = absenceResult.removedCount;
+  allChangeLogs.push(...absenceResult.changeLog);
+
+  // 9. Remove invalid and expired topic delegations
+  const delegationResult = removeInvalidDelegations(currentDatastore);
+  currentDatastore = delegationResult.datastore;
+  totalRemovedDelegations = delegationResult.removedCount;
+  allChangeLogs.push(...delegationResult.changeLog);
+
+  // 10. Clean topic rotations
+  const rotationResult = cleanTopicRotations(currentDatastore);
+  currentDatastore = rotationResult.datastore;
+  totalCorrectedRotations = rotationResult.correctedCount;
+  allChangeLogs.push(...rotationResult.changeLog);
+
+  // 11. Remove unknown tags from automatic rules
+  const ruleTagResult = removeInvalidRuleTags(currentDatastore);
+  currentDatastore = ruleTagResult.datastore;
+  totalRemovedRuleTags = ruleTagResult.removedCount;
+  allChangeLogs.push(...ruleTagResult.changeLog);
+
+  const hasChanges = totalRemovedTags > 0 || totalRemovedMembers > 0 || totalCorrectedFields > 0 || totalCorrectedMemberColors > 0 || totalCorrectedTagColors > 0 || totalRemovedConnections > 0 || totalNormalizedTimestamps > 0 || totalRemovedAbsences > 0 || totalRemovedDelegations > 0 || totalCorrectedRotations > 0 || totalRemovedRuleTags > 0;
 
   if (hasChanges) {
     console.log('[Plausibility] Cleaned up datastore:', {
@@ -448,6 +766,11 @@ export function runPlausibilityChecks(datastore: Datastore): {
       correctedMemberColors: totalCorrectedMemberColors,
       correctedTagColors: totalCorrectedTagColors,
       removedTopicConnections: totalRemovedConnections,
+      normalizedTimestamps: totalNormalizedTimestamps,
+      removedAbsences: totalRemovedAbsences,
+      removedDelegations: totalRemovedDelegations,
+      correctedRotations: totalCorrectedRotations,
+      removedRuleTags: totalRemovedRuleTags,
       changeLog: allChangeLogs,
     });
   }
@@ -462,6 +785,11 @@ export function runPlausibilityChecks(datastore: Datastore): {
       correctedMemberColors: totalCorrectedMemberColors,
       correctedTagColors: totalCorrectedTagColors,
       removedTopicConnections: totalRemovedConnections,
+      normalizedTimestamps: totalNormalizedTimestamps,
+      removedAbsences: totalRemovedAbsences,
+      removedDelegations: totalRemovedDelegations,
+      correctedRotations: totalCorrectedRotations,
+      removedRuleTags: totalRemovedRuleTags,
       changeLog: allChangeLogs,
     },
   };
@@ -4,6 +4,7 @@ import { Datastore, Topic, TeamMember, Tag, LockPurpose } from '../models';
 import { FileConnectionService } from './file-connection.service';
 import { LockService } from './lock.service';
 import { RefreshService } from './refresh.service';
+import { replaceRuleTag } from '../../shared/utils/auto-rules.utils';
 
 @Injectable({
   providedIn: 'root'
@@ -43,6 +44,7 @@ export class DatastoreService {
           schemaVersion: 1,
           generatedAt: new Date().toISOString(),
           revisionId: 0,
+          instanceId: this.generateUUID(),
           members: [],
           topics: [],
           tags: []
@@ -266,8 +268,11 @@ export class DatastoreService {
           datastore.tags[index] = { ...datastore.tags[index], ...updates, modifiedAt: new Date().toISOString() };
           const newName = datastore.tags[index].name;
           
-          // Update tag references in topics if name changed
+          // Update tag references in topics and rule actions if name changed
           if (oldName !== newName) {
+            if (datastore.autoRules) {
+              datastore.autoRules = replaceRuleTag(datastore.autoRules, oldName, newName);
+            }
             datastore.topics = datastore.topics.map(topic => {
               if (topic.tags && topic.tags.includes(oldName)) {
                 return {
@@ -303,6 +308,9 @@ export class DatastoreService {
             }
             return topic;
           });
+          if (datastore.autoRules) {
+            datastore.autoRules = replaceRuleTag(datastore.autoRules, tagToDelete.name, null);
+          }
           // Remove the tag itself
           datastore.tags = datastore.tags.filter(t => t.id !== tagId);
         }
@@ -13,7 +13,7 @@ export class FileConnectionError extends Error {
   constructor(
     message: string,
     public readonly germanMessage: string,
-    public readonly code: 'NOT_CONNECTED' | 'READ_ERROR' | 'WRITE_ERROR' | 'PERMISSION_DENIED' | 'FILE_NOT_FOUND'
+    public readonly code: 'NOT_CONNECTED' | 'READ_ERROR' | 'WRITE_ERROR' | 'PERMISSION_DENIED' | 'FILE_NOT_FOUND' | 'INSUFFICIENT_STORAGE'
   ) {
     super(message);
     this.name = 'FileConnectionError';
@@ -182,13 +182,26 @@ export class FileConnectionService {
   /**
    * Write file content with consistent error handling.
    * Uses createWritable, write full JSON, close pattern.
+   * The browser writes to a temporary file and only replaces the target on close,
+   * so a failed write (e.g. disk full) leaves the existing file intact.
    */
   async writeFile(handle: FileSystemFileHandle, content: string): Promise<void> {
+    let writable: FileSystemWritableFileStream | null = null;
     try {
-      const writable = await handle.createWritable();
+      writable = await handle.createWritable();
       await writable.write(content);
       await writable.close();
     } catch (error: any) {
+      // Discard the temporary file of the failed write
+      await writable?.abort().catch(() => undefined);
+
+      if (error.name === 'QuotaExceededError') {
+        throw new FileConnectionError(
+          'Insufficient storage writing file',
+          'Nicht genügend Speicherplatz im Datenverzeichnis. Die Änderungen wurden nicht gespeichert, die bestehenden Daten sind unverändert. Bitte geben Sie Speicherplatz frei (z.B. alte Dateien im Ordner "backup") und speichern Sie erneut.',
+          'INSUFFICIENT_STORAGE'
+        );
+      }
       if (error.name === 'NotAllowedError') {
         throw new FileConnectionError(
           'Permission denied writing file',
@@ -1,7 +1,7 @@
 import { Injectable, inject } from '@angular/core';
 import { BehaviorSubject, Observable, Subscription } from 'rxjs';
 import { BackendService } from './backend.service';
-import { Datastore, Topic, TeamMember, Tag } from '../models';
+import { Datastore, Topic, TeamMember, Tag, RaciDefaults, AutoRule, ReferencePolicy, SearchStopWords } from '../models';
 import { FileConnectionService } from './file-connection.service';
 import { CacheService } from './cache.service';
 import { PersistenceService } from './persistence.service';
@@ -100,6 +100,28 @@ export class FileSystemBackendService extends BackendService {
     return result.success;
   }
 
+  // ==================== WORKSPACE SETTINGS ====================
+
+  async updateRaciDefaults(raciDefaults: RaciDefaults): Promise<boolean> {
+    const result = this.cache.updateRaciDefaults(raciDefaults);
+    return result.success;
+  }
+
+  async updateAutoRules(autoRules: AutoRule[]): Promise<boolean> {
+    const result = this.cache.updateAutoRules(autoRules);
+    return result.success;
+  }
+
+  async updateReferencePolicy(referencePolicy: ReferencePolicy): Promise<boolean> {
+    const result = this.cache.updateReferencePolicy(referencePolicy);
+    return result.success;
+  }
+
+  async updateSearchStopWords(searchStopWords: SearchStopWords): Promise<boolean> {
+    const result = this.cache.updateSearchStopWords(searchStopWords);
+    return result.success;
+  }
+
   // ==================== MEMBER OPERATIONS ====================
 
   async addMember(member: TeamMember): Promise<boolean> {
@@ -3,6 +3,10 @@
  * 
  * Periodically validates that the search index matches the current datastore
  * by comparing checksums. Triggers a rebuild when changes are detected.
+ *
+ * The index itself lives in memory only and is rebuilt from the datastore on
+ * every page load, so an index never outlives a release and needs no schema
+ * version. The stored checksum only prevents redundant rebuilds while the page is open.
  */
 
 import { Injectable, OnDestroy, signal, computed } from '@angular/core';
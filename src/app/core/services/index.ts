@@ -4,6 +4,7 @@ export * from './refresh.service';
 export * from './datastore.service';
 export * from './datastore-commit.service';
 export * from './datastore-plausibility';
+export * from './datastore-export';
 export * from './search-index.service';
 export * from './backend.service';
 export * from './file-system-backend.service';
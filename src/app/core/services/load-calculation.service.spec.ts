@@ -664,6 +664,47 @@ describe('LoadCalculationService', () => {
     });
   });
 
+  describe('findOverloadedByChange', () => {
+    const manyTopics = (count: number, memberId: string): Topic[] =>
+      Array.from({ length: count }, (_, i) =>
+        createTopic({ id: `topic-${i}`, raci: { r1MemberId: memberId, cMemberIds: [], iMemberIds: [] } })
+      );
+
+    it('should report a member overloaded by an added assignment', () => {
+      const member = createMember();
+      // Default capacity 41 * (1 - 0.35) = 26.65: 3.5 + 5 * 3.0 is normal, 3.5 + 10 * 3.0 is above capacity
+      const before = manyTopics(5, 'member-1');
+      const after = manyTopics(10, 'member-1');
+
+      const result = service.findOverloadedByChange([member], before, after, []);
+
+      expect(result.map((r) => r.memberId)).toEqual(['member-1']);
+    });
+
+    it('should not report members whose load does not increase', () => {
+      const member = createMember();
+      const topics = manyTopics(200, 'member-1');
+
+      const result = service.findOverloadedByChange([member], topics, topics, []);
+
+      expect(result).toEqual([]);
+    });
+
+    it('should not report members below capacity', () => {
+      const result = service.findOverloadedByChange([createMember()], [], manyTopics(1, 'member-1'), []);
+
+      expect(result).toEqual([]);
+    });
+
+    it('should not report inactive members', () => {
+      const member = createMember({ active: false });
+
+      const result = service.findOverloadedByChange([member], [], manyTopics(500, 'member-1'), []);
+
+      expect(result).toEqual([]);
+    });
+  });
+
   describe('getFormulaExplanation', () => {
     it('should return formula explanation text', () => {
       // Reset mock to return null so defaults are used
@@ -364,6 +364,52 @@ export class LoadCalculationService {
       return this.cache.result;
     }
 
+    const result = this.computeLoad(members, topics, tags, loadConfig);
+
+    // Update cache
+    this.cache = {
+      key: { revisionId, configHash },
+      result,
+    };
+
+    return result;
+  }
+
+  /**
+   * Finds active members who are overloaded ('overloaded' or 'unsustainable') after a
+   * change of topics and whose load is increased by it, e.g. by a new RACI assignment.
+   * Used for warnings when saving. Does not use or modify the cache.
+   * @returns Load results after the change for the affected members
+   */
+  findOverloadedByChange(
+    members: TeamMember[],
+    topicsBefore: Topic[],
+    topicsAfter: Topic[],
+    tags: Tag[],
+    config?: LoadConfig | null
+  ): MemberLoadResult[] {
+    const loadConfig = config ?? this.loadConfigService.getConfig();
+    const before = new Map(
+      this.computeLoad(members, topicsBefore, tags, loadConfig).memberLoads.map((ml) => [ml.memberId, ml.capacityRatio])
+    );
+
+    return this.computeLoad(members, topicsAfter, tags, loadConfig).memberLoads.filter(
+      (after) =>
+        after.isActive &&
+        (after.loadStatus === 'overloaded' || after.loadStatus === 'unsustainable') &&
+        after.capacityRatio > (before.get(after.memberId) ?? 0) + 1e-9
+    );
+  }
+
+  /**
+   * Uncached load calculation for all members.
+   */
+  private computeLoad(
+    members: TeamMember[],
+    topics: Topic[],
+    tags: Tag[],
+    loadConfig: LoadConfig | null
+  ): LoadCalculationResult {
     // Get configuration values
     const alpha = loadConfig?.topicComplexity.alpha ?? COMPLEXITY_CONSTANTS.ALPHA;
     const beta = loadConfig?.topicComplexity.beta ?? COMPLEXITY_CONSTANTS.BETA;
@@ -485,7 +531,7 @@ export class LoadCalculationService {
     // Validate data and collect warnings
     const warnings = this.validateData(topics, members, tags);
 
-    const result: LoadCalculationResult = {
+    return {
       memberLoads,
       medianLoad,
       effectiveFullTimeCapacity,
@@ -497,14 +543,6 @@ export class LoadCalculationService {
       contractHoursPerWeek,
       overheadFactor,
     };
-
-    // Update cache
-    this.cache = {
-      key: { revisionId, configHash },
-      result,
-    };
-
-    return result;
   }
 
   /**
@@ -0,0 +1,152 @@
+import { describe, it, expect, beforeAll } from 'vitest';
+import { SearchEngineService } from './search-engine.service';
+import { computeDatastoreChecksum } from './checksum';
+import { runPlausibilityChecks } from './datastore-plausibility';
+import { Datastore, Topic, TeamMember, Tag } from '../models';
+
+/**
+ * Performance regression suite.
+ *
+ * Seeds a datastore with 10,000 topics and measures the operations that scale with
+ * datastore size. Skipped by default; run with `PERF_TESTS=1 npm test`.
+ * Timings are printed as a table so changes can be compared before/after;
+ * the budgets only catch severe regressions.
+ */
+
+// Mock localStorage
+const localStorageMock = (() => {
+  let store: Record<string, string> = {};
+  return {
+    getItem: (key: string) => store[key] || null,
+    setItem: (key: string, value: string) => { store[key] = value; },
+    removeItem: (key: string) => { delete store[key]; },
+    clear: () => { store = {}; }
+  };
+})();
+Object.defineProperty(globalThis, 'localStorage', { value: localStorageMock });
+
+const TOPIC_COUNT = 10_000;
+const MEMBER_COUNT = 50;
+const TAG_COUNT = 100;
+
+/** Budgets in ms (generous, to avoid flaky failures on slow machines) */
+const BUDGET_MS = {
+  buildIndex: 30_000,
+  search: 100,
+  advancedSearch: 250,
+  checksum: 5_000,
+  plausibility: 2_000
+};
+
+const WORDS = [
+  'Urlaub', 'Antrag', 'Passwort', 'Reset', 'Drucker', 'Server', 'Backup', 'Vertrag',
+  'Rechnung', 'Beschaffung', 'Schulung', 'Datenschutz', 'Zugang', 'Netzwerk', 'Telefon',
+  'Gehalt', 'Reisekosten', 'Archiv', 'Postfach', 'Lizenz', 'Wartung', 'Fortbildung'
+];
+
+function word(seed: number): string {
+  return WORDS[seed % WORDS.length];
+}
+
+function createSeedDatastore(): Datastore {
+  const now = new Date().toISOString();
+  const members: TeamMember[] = Array.from({ length: MEMBER_COUNT }, (_, i) => ({
+    id: `member-${i}`,
+    displayName: `Mitglied ${i} ${word(i * 7)}`,
+    active: true,
+    updatedAt: now
+  }));
+  const tags: Tag[] = Array.from({ length: TAG_COUNT }, (_, i) => ({
+    id: `tag-${i}`,
+    name: `${word(i)}-${i}`,
+    searchKeywords: [word(i + 3)],
+    createdAt: now,
+    modifiedAt: now,
+    createdBy: 'member-0'
+  }));
+  const topics: Topic[] = Array.from({ length: TOPIC_COUNT }, (_, i) => ({
+    id: `topic-${i}`,
+    header: `${word(i)} ${word(i * 3 + 1)} ${i}`,
+    description: `${word(i + 5)} und ${word(i + 11)} für Bereich ${i % 37}`,
+    searchKeywords: [word(i + 2), word(i + 9)],
+    notes: i % 4 === 0 ? `Hinweis zu ${word(i + 13)}` : undefined,
+    tags: [tags[i % TAG_COUNT].name],
+    validity: { alwaysValid: true },
+    raci: {
+      r1MemberId: `member-${i % MEMBER_COUNT}`,
+      r2MemberId: `member-${(i + 1) % MEMBER_COUNT}`,
+      cMemberIds: [`member-${(i + 2) % MEMBER_COUNT}`],
+      iMemberIds: []
+    },
+    updatedAt: now
+  }));
+
+  return { schemaVersion: 1, generatedAt: now, revisionId: 1, members, topics, tags };
+}
+
+/** Runs fn `runs` times and returns the median duration in ms. */
+async function measureMedian(runs: number, fn: () => unknown): Promise<number> {
+  const durations: number[] = [];
+  for (let i = 0; i < runs; i++) {
+    const start = performance.now();
+    await fn();
+    durations.push(performance.now() - start);
+  }
+  durations.sort((a, b) => a - b);
+  return durations[Math.floor(durations.length / 2)];
+}
+
+describe.skipIf(!process.env['PERF_TESTS'])(`Performance (${TOPIC_COUNT} topics)`, () => {
+  const timings: Record<string, number> = {};
+  let datastore: Datastore;
+  let service: SearchEngineService;
+
+  beforeAll(() => {
+    localStorageMock.clear();
+    datastore = createSeedDatastore();
+    service = new SearchEngineService();
+  });
+
+  it('should build the search index within budget', async () => {
+    timings['buildIndex'] = await measureMedian(1, () => service.buildIndex(datastore));
+
+    expect(service.getIndexSize()).toBe(TOPIC_COUNT);
+    expect(timings['buildIndex']).toBeLessThan(BUDGET_MS.buildIndex);
+  }, 120_000);
+
+  it('should search within budget', async () => {
+    timings['search'] = await measureMedian(20, () => service.search('Urlaub Antrag', 20));
+    timings['searchMember'] = await measureMedian(20, () => service.search('Mitglied 7', 20));
+
+    expect(service.search('Urlaub', 20).length).toBeGreaterThan(0);
+    expect(timings['search']).toBeLessThan(BUDGET_MS.search);
+    expect(timings['searchMember']).toBeLessThan(BUDGET_MS.search);
+  });
+
+  it('should evaluate advanced queries within budget', async () => {
+    timings['advancedSearch'] = await measureMedian(20, () =>
+      service.search('+urlaub -drucker "passwort reset" tags:Server', 20)
+    );
+
+    expect(timings['advancedSearch']).toBeLessThan(BUDGET_MS.advancedSearch);
+  });
+
+  it('should compute the datastore checksum within budget', async () => {
+    timings['checksum'] = await measureMedian(3, () => computeDatastoreChecksum(datastore));
+
+    expect(timings['checksum']).toBeLessThan(BUDGET_MS.checksum);
+  });
+
+  it('should run plausibility checks within budget', async () => {
+    timings['plausibility'] = await measureMedian(3, () => runPlausibilityChecks(datastore));
+
+    expect(timings['plausibility']).toBeLessThan(BUDGET_MS.plausibility);
+  });
+
+  it('should report timings', () => {
+    console.table(
+      Object.entries(timings).map(([operation, ms]) => ({ operation, medianMs: Math.round(ms * 10) / 10 }))
+    );
+    expect(Object.keys(timings).length).toBeGreaterThan(0);
+  });
+});
@@ -22,6 +22,12 @@ export interface PersistenceResult {
  */
 const AUTO_SAVE_INTERVAL_MS = 60000;
 
+/**
+ * Interval for re-reading datastore.json to detect a replaced datastore.
+ * Restoring a backup does not touch refresh.json, so the refresh signal alone misses it.
+ */
+const REPLACEMENT_CHECK_INTERVAL_MS = 60000;
+
 /**
  * PersistenceService - Handles backend communication
  * 
@@ -56,6 +62,7 @@ export class PersistenceService {
   constructor() {
     this.setupAutoSave();
     this.setupRefreshListener();
+    this.setupReplacementCheck();
   }
 
   /**
@@ -71,6 +78,19 @@ export class PersistenceService {
       });
   }
 
+  /**
+   * Periodically check whether datastore.json was replaced (e.g. restored from backup).
+   */
+  private setupReplacementCheck(): void {
+    interval(REPLACEMENT_CHECK_INTERVAL_MS)
+      .pipe(takeUntilDestroyed(this.destroyRef))
+      .subscribe(() => {
+        if (this.fileConnection.isConnected()) {
+          void this.checkForExternalChanges();
+        }
+      });
+  }
+
   /**
    * Listen for refresh triggers from backend.
    */
@@ -136,20 +156,26 @@ export class PersistenceService {
         console.log('[PersistenceService] Plausibility checks made corrections:', plausibilityResult.changeLog);
       }
 
-      // Commit to backend
+      // Commit to backend; the cache replaces the whole datastore, so the commit
+      // is rejected if the file was changed or replaced since it was loaded
       const result = await this.commitService.commitChanges(
         () => cleanedDatastore,
-        'topic-save'  // Default purpose, could be refined based on changes
+        'topic-save',  // Default purpose, could be refined based on changes
+        { instanceId: datastoreToSave.instanceId, revisionId: this.cache.getCacheState().revisionId }
       );
 
       if (result.success) {
         // Update cache to reflect successful save
         const newRevisionId = result.datastore?.revisionId || cleanedDatastore.revisionId + 1;
-        this.cache.markAsSynced(newRevisionId);
+        this.cache.markAsSynced(newRevisionId, result.datastore?.instanceId);
         this.lastSaveTime.set(new Date().toISOString());
         
         return { success: true, germanMessage: 'Änderungen erfolgreich gespeichert' };
       } else {
+        if (result.conflict && result.datastore) {
+          // Surface the backend version as a conflict with the local changes
+          this.cache.handleExternalChanges(result.datastore);
+        }
         this.lastError.set(result.germanMessage);
         return { success: false, germanMessage: result.germanMessage };
       }
@@ -176,9 +202,24 @@ export class PersistenceService {
       
       if (result.success && result.datastore) {
         const currentCache = this.cache.getCacheState();
-        
-        // Check if backend has newer revision
-        if (result.datastore.revisionId > currentCache.revisionId) {
+        const cachedInstanceId = currentCache.datastore?.instanceId;
+
+        // A different instance or a lower revision means the datastore was replaced
+        // (e.g. restored from backup) - revision numbers are not comparable, reload fully
+        const replaced =
+          (cachedInstanceId !== undefined && result.datastore.instanceId !== cachedInstanceId) ||
+          result.datastore.revisionId < currentCache.revisionId;
+
+        if (replaced) {
+          console.warn('[PersistenceService] Datastore was replaced, reloading:', {
+            cachedInstanceId,
+            backendInstanceId: result.datastore.instanceId,
+            cachedRevision: currentCache.revisionId,
+            backendRevision: result.datastore.revisionId
+          });
+          this.cache.handleExternalChanges(result.datastore);
+        } else if (result.datastore.revisionId > currentCache.revisionId) {
+          // Check if backend has newer revision
           this.cache.handleExternalChanges(result.datastore);
         }
       }
@@ -199,6 +240,7 @@ export class PersistenceService {
    * Set the current user identity for commits.
    */
   setCurrentUser(memberId: string, displayName: string): void {
+    this.cache.setCurrentUser(memberId);
     this.commitService.setCurrentUser(memberId, displayName);
   }
 
@@ -0,0 +1,219 @@
+import { describe, it, expect, beforeAll } from 'vitest';
+import { computeDatastoreChecksum } from './checksum';
+import { runPlausibilityChecks } from './datastore-plausibility';
+import { parseSearchQuery, SearchQueryError } from './search-query';
+import { SearchEngineService } from './search-engine.service';
+import { normalizeTimestamp } from '../../shared/utils/validation.utils';
+import { Datastore, Topic, TeamMember, Tag, TShirtSize, TopicConnectionType } from '../models';
+
+/**
+ * Property-based tests: model round-trips and query parser fuzzing.
+ *
+ * Inputs are generated from a seeded PRNG so failures are reproducible;
+ * the failing seed is part of the assertion message.
+ */
+
+// Mock localStorage
+const localStorageMock = (() => {
+  let store: Record<string, string> = {};
+  return {
+    getItem: (key: string) => store[key] || null,
+    setItem: (key: string, value: string) => { store[key] = value; },
+    removeItem: (key: string) => { delete store[key]; },
+    clear: () => { store = {}; }
+  };
+})();
+Object.defineProperty(globalThis, 'localStorage', { value: localStorageMock });
+
+const RUNS = 200;
+
+/** Small deterministic PRNG (mulberry32) */
+function createRandom(seed: number) {
+  let state = seed >>> 0;
+  const next = (): number => {
+    state = (state + 0x6d2b79f5) >>> 0;
+    let t = state;
+    t = Math.imul(t ^ (t >>> 15), t | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+  const int = (min: number, max: number): number => min + Math.floor(next() * (max - min + 1));
+  const pick = <T>(items: readonly T[]): T => items[int(0, items.length - 1)];
+  const bool = (): boolean => next() < 0.5;
+  const maybe = <T>(value: () => T): T | undefined => (bool() ? value() : undefined);
+  const string = (alphabet: string, maxLength: number): string =>
+    Array.from({ length: int(0, maxLength) }, () => alphabet[int(0, alphabet.length - 1)]).join('');
+  return { next, int, pick, bool, maybe, string };
+}
+
+type Random = ReturnType<typeof createRandom>;
+
+const TEXT_ALPHABET = 'abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZäöüÄÖÜß0123456789 -_.,;:!?"\'/\\()[]{}€@#\n\t';
+const QUERY_ALPHABET = 'abcäöüß "+-:*~HRtags';
+const SIZES: TShirtSize[] = ['XXS', 'XS', 'S', 'M', 'L', 'XL', 'XXL'];
+const CONNECTION_TYPES: TopicConnectionType[] = ['dependsOn', 'blocks', 'relatedTo'];
+
+function arbitraryTimestamp(random: Random): string {
+  return new Date(random.int(0, 4_102_444_800_000)).toISOString();
+}
+
+function arbitraryDatastore(random: Random): Datastore {
+  const members: TeamMember[] = Array.from({ length: random.int(1, 6) }, (_, i) => ({
+    id: `member-${i}`,
+    displayName: random.string(TEXT_ALPHABET, 20) || `Member ${i}`,
+    email: random.maybe(() => `m${i}@example.org`),
+    active: random.bool(),
+    color: random.maybe(() => random.pick(['#ff0000', '#0a0', '#12345678'])),
+    tags: random.maybe(() => [random.string(TEXT_ALPHABET, 8)]),
+    updatedAt: arbitraryTimestamp(random)
+  }));
+
+  const tags: Tag[] = Array.from({ length: random.int(0, 5) }, (_, i) => ({
+    id: `tag-${i}`,
+    name: `tag${i}`,
+    searchKeywords: random.maybe(() => [random.string(TEXT_ALPHABET, 10)]),
+    hinweise: random.maybe(() => random.string(TEXT_ALPHABET, 40)),
+    color: random.maybe(() => '#00ff00'),
+    createdAt: arbitraryTimestamp(random),
+    modifiedAt: arbitraryTimestamp(random),
+    createdBy: 'member-0'
+  }));
+
+  const memberId = () => `member-${random.int(0, members.length - 1)}`;
+  const topicCount = random.int(0, 8);
+  const topics: Topic[] = Array.from({ length: topicCount }, (_, i) => ({
+    id: `topic-${i}`,
+    header: random.string(TEXT_ALPHABET, 30) || `Topic ${i}`,
+    description: random.maybe(() => random.string(TEXT_ALPHABET, 80)),
+    tags: random.maybe(() => tags.filter(() => random.bool()).map(t => t.name)),
+    searchKeywords: random.maybe(() => [random.string(TEXT_ALPHABET, 10), random.string(TEXT_ALPHABET, 10)]),
+    notes: random.maybe(() => random.string(TEXT_ALPHABET, 80)),
+    validity: random.bool()
+      ? { alwaysValid: true }
+      : { alwaysValid: false, validFrom: '2024-01-01', validTo: random.maybe(() => '2030-12-31') },
+    raci: {
+      r1MemberId: memberId(),
+      r2MemberId: random.maybe(memberId),
+      r3MemberId: random.maybe(memberId),
+      cMemberIds: random.bool() ? [memberId()] : [],
+      iMemberIds: random.bool() ? [memberId()] : []
+    },
+    priority: random.maybe(() => random.int(1, 10)),
+    size: random.maybe(() => random.pick(SIZES)),
+    connections: random.maybe(() =>
+      topicCount > 1
+        ? [{ targetTopicId: `topic-${(i + 1) % topicCount}`, type: random.pick(CONNECTION_TYPES) }]
+        : []
+    ),
+    updatedAt: arbitraryTimestamp(random)
+  }));
+
+  return {
+    schemaVersion: 1,
+    generatedAt: arbitraryTimestamp(random),
+    revisionId: random.int(0, 10_000),
+    members,
+    topics,
+    tags
+  };
+}
+
+describe('Datastore round-trip properties', () => {
+  it('should survive a JSON write/read cycle unchanged', async () => {
+    for (let seed = 1; seed <= RUNS; seed++) {
+      const datastore = arbitraryDatastore(createRandom(seed));
+      const reloaded = JSON.parse(JSON.stringify(datastore, null, 2)) as Datastore;
+
+      expect(reloaded, `seed ${seed}`).toEqual(datastore);
+      expect(await computeDatastoreChecksum(reloaded), `seed ${seed}`).toBe(
+        await computeDatastoreChecksum(datastore)
+      );
+    }
+  });
+
+  it('should not change valid generated datastores in plausibility checks', () => {
+    for (let seed = 1; seed <= RUNS; seed++) {
+      const datastore = arbitraryDatastore(createRandom(seed));
+      const { datastore: cleaned, result } = runPlausibilityChecks(datastore);
+
+      // Generated topics only reference existing members, tags and topics
+      expect(result.removedMemberReferences, `seed ${seed}`).toBe(0);
+      expect(result.removedTopicConnections, `seed ${seed}`).toBe(0);
+      expect(result.normalizedTimestamps, `seed ${seed}`).toBe(0);
+      expect(cleaned.topics, `seed ${seed}`).toEqual(datastore.topics);
+    }
+  });
+
+  it('should make plausibility checks idempotent', () => {
+    for (let seed = 1; seed <= RUNS; seed++) {
+      const random = createRandom(seed);
+      const datastore = arbitraryDatastore(random);
+      // Inject inconsistencies
+      datastore.topics.forEach(topic => {
+        topic.raci.cMemberIds.push('missing-member');
+        topic.priority = random.pick([0, 5, 11, undefined]);
+        topic.updatedAt = random.pick([topic.updatedAt, '2024-05-01 08:30:00', '2024-05-01T10:30:00+02:00']);
+      });
+
+      const first = runPlausibilityChecks(datastore);
+      const second = runPlausibilityChecks(first.datastore);
+
+      expect(second.result.hasChanges, `seed ${seed}`).toBe(false);
+      expect(second.datastore, `seed ${seed}`).toEqual(first.datastore);
+    }
+  });
+});
+
+describe('Timestamp normalization properties', () => {
+  it('should be idempotent and keep the instant', () => {
+    for (let seed = 1; seed <= RUNS; seed++) {
+      const timestamp = arbitraryTimestamp(createRandom(seed));
+      const withOffset = timestamp.replace('Z', '+00:00').replace(/\.\d{3}/, '');
+
+      const normalized = normalizeTimestamp(withOffset);
+
+      expect(normalized, `seed ${seed}`).toBeDefined();
+      expect(normalizeTimestamp(normalized), `seed ${seed}`).toBe(normalized);
+      expect(Date.parse(normalized!), `seed ${seed}`).toBe(Date.parse(withOffset));
+    }
+  });
+});
+
+describe('Search query fuzzing', () => {
+  let service: SearchEngineService;
+
+  beforeAll(async () => {
+    localStorageMock.clear();
+    service = new SearchEngineService();
+    await service.buildIndex(arbitraryDatastore(createRandom(42)));
+  });
+
+  it('should either parse or throw SearchQueryError', () => {
+    for (let seed = 1; seed <= RUNS * 5; seed++) {
+      const query = createRandom(seed).string(QUERY_ALPHABET, 30);
+      try {
+        const parsed = parseSearchQuery(query);
+        for (const clause of parsed.clauses) {
+          expect(clause.text.trim(), `seed ${seed}: ${JSON.stringify(query)}`).not.toBe('');
+        }
+      } catch (error) {
+        expect(error, `seed ${seed}: ${JSON.stringify(query)}`).toBeInstanceOf(SearchQueryError);
+        const position = (error as SearchQueryError).position;
+        expect(position).toBeGreaterThanOrEqual(0);
+        expect(position).toBeLessThan(query.length);
+      }
+    }
+  });
+
+  it('should never fail with anything but SearchQueryError when searching', () => {
+    for (let seed = 1; seed <= RUNS; seed++) {
+      const query = createRandom(seed).string(QUERY_ALPHABET + TEXT_ALPHABET, 40);
+      try {
+        const hits = service.search(query, 10);
+        expect(hits.length, `seed ${seed}`).toBeLessThanOrEqual(10);
+      } catch (error) {
+        expect(error, `seed ${seed}: ${JSON.stringify(query)}`).toBeInstanceOf(SearchQueryError);
+      }
+    }
+  });
+});
@@ -1,7 +1,7 @@
 import { Injectable } from '@angular/core';
 import { BehaviorSubject, Observable } from 'rxjs';
 import { BackendService } from './backend.service';
-import { Datastore, Topic, TeamMember, Tag, LockPurpose } from '../models';
+import { Datastore, Topic, TeamMember, Tag, LockPurpose, RaciDefaults, AutoRule, ReferencePolicy, SearchStopWords } from '../models';
 
 /**
  * REST API implementation of the backend (placeholder).
@@ -203,6 +203,70 @@ export class RestBackendService extends BackendService {
     }
   }
 
+  async updateRaciDefaults(raciDefaults: RaciDefaults): Promise<boolean> {
+    try {
+      // Example REST call:
+      // const response = await fetch(`${this.apiBaseUrl}/settings/raci-defaults`, {
+      //   method: 'PUT',
+      //   headers: { 'Content-Type': 'application/json' },
+      //   body: JSON.stringify(raciDefaults)
+      // });
+      console.log('REST: Updating RACI defaults via API', raciDefaults);
+      return false;
+    } catch (error) {
+      console.error('Failed to update RACI defaults via API:', error);
+      return false;
+    }
+  }
+
+  async updateAutoRules(autoRules: AutoRule[]): Promise<boolean> {
+    try {
+      // Example REST call:
+      // const response = await fetch(`${this.apiBaseUrl}/settings/rules`, {
+      //   method: 'PUT',
+      //   headers: { 'Content-Type': 'application/json' },
+      //   body: JSON.stringify(autoRules)
+      // });
+      console.log('REST: Updating rules via API', autoRules);
+      return false;
+    } catch (error) {
+      console.error('Failed to update rules via API:', error);
+      return false;
+    }
+  }
+
+  async updateReferencePolicy(referencePolicy: ReferencePolicy): Promise<boolean> {
+    try {
+      // Example REST call:
+      // const response = await fetch(`${this.apiBaseUrl}/settings/reference-policy`, {
+      //   method: 'PUT',
+      //   headers: { 'Content-Type': 'application/json' },
+      //   body: JSON.stringify(referencePolicy)
+      // });
+      console.log('REST: Updating reference policy via API', referencePolicy);
+      return false;
+    } catch (error) {
+      console.error('Failed to update reference policy via API:', error);
+      return false;
+    }
+  }
+
+  async updateSearchStopWords(searchStopWords: SearchStopWords): Promise<boolean> {
+    try {
+      // Example REST call:
+      // const response = await fetch(`${this.apiBaseUrl}/settings/stop-words`, {
+      //   method: 'PUT',
+      //   headers: { 'Content-Type': 'application/json' },
+      //   body: JSON.stringify(searchStopWords)
+      // });
+      console.log('REST: Updating stop words via API', searchStopWords);
+      return false;
+    } catch (error) {
+      console.error('Failed to update stop words via API:', error);
+      return false;
+    }
+  }
+
   generateUUID(): string {
     return 'xxxxxxxx-xxxx-4xxx-yxxx-xxxxxxxxxxxx'.replace(/[xy]/g, function(c) {
       const r = Math.random() * 16 | 0;
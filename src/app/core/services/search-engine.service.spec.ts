@@ -1,5 +1,6 @@
 import { describe, it, expect, beforeEach, vi } from 'vitest';
 import { SearchEngineService, createDocumentId, parseDocumentId, SearchHit } from './search-engine.service';
+import { SearchQueryError } from './search-query';
 import { Datastore, Topic, Tag, TeamMember } from '../models';
 
 // Mock localStorage
@@ -165,6 +166,57 @@ describe('SearchEngineService', () => {
       expect(results[0].entityId).toBe('t1');
     });
 
+    it('should restrict the search to the given fields', async () => {
+      const ds = createDatastore({
+        topics: [
+          createTopic('t1', 'Urlaubsantrag'),
+          createTopic('t2', 'Generic Title', { description: 'Urlaubsantrag stellen' })
+        ]
+      });
+      await service.buildIndex(ds);
+
+      const results = service.search('Urlaubsantrag', 10, ['title']);
+      expect(results.map(r => r.entityId)).toEqual(['t1']);
+    });
+
+    it('should not restrict explicit field clauses', async () => {
+      const ds = createDatastore({
+        topics: [createTopic('t1', 'Generic Title', { description: 'Urlaubsantrag stellen' })]
+      });
+      await service.buildIndex(ds);
+
+      const results = service.search('description:Urlaubsantrag', 10, ['title']);
+      expect(results.map(r => r.entityId)).toEqual(['t1']);
+    });
+
+    it('should collapse near-identical topics into the best hit', async () => {
+      const ds = createDatastore({
+        topics: [
+          createTopic('t1', 'Urlaubsantrag', { description: 'Antrag stellen' }),
+          createTopic('t2', 'urlaubsantrag', { description: 'Antrag stellen.' }),
+          createTopic('t3', 'Urlaubsantrag', { description: 'Antrag prüfen' })
+        ]
+      });
+      await service.buildIndex(ds);
+
+      const results = service.search('Urlaubsantrag');
+      expect(results).toHaveLength(2);
+      const primary = results.find(r => r.duplicates);
+      expect(primary?.duplicates).toHaveLength(1);
+      expect([primary?.entityId, primary?.duplicates?.[0].entityId].sort()).toEqual(['t1', 't2']);
+    });
+
+    it('should ignore configured stop words', async () => {
+      const ds = createDatastore({
+        topics: [createTopic('t1', 'Abtx Urlaubsantrag')],
+        searchStopWords: { useGermanList: false, custom: ['abtx'] }
+      });
+      await service.buildIndex(ds);
+
+      expect(service.search('abtx')).toEqual([]);
+      expect(service.search('Urlaub').length).toBeGreaterThan(0);
+    });
+
     it('should find topic by searchKeywords', async () => {
       const ds = createDatastore({
         topics: [createTopic('t1', 'Vacation Request', {
@@ -218,6 +270,42 @@ describe('SearchEngineService', () => {
       expect(results[0].entityId).toBe('t1');
     });
 
+    it('should find topic by RACI member display name', async () => {
+      const ds = createDatastore({
+        topics: [createTopic('t1', 'Urlaubsanträge genehmigen', {
+          raci: { r1MemberId: 'm1', r2MemberId: 'm2', cMemberIds: [], iMemberIds: ['m3'] }
+        })],
+        members: [
+          createMember('m1', 'Anna Müller'),
+          createMember('m2', 'Bernd Schulz'),
+          createMember('m3', 'Clara Weiss')
+        ]
+      });
+      await service.buildIndex(ds);
+
+      expect(service.search('Müller')[0]?.entityId).toBe('t1');
+      expect(service.search('Schulz')[0]?.entityId).toBe('t1');
+      expect(service.search('Weiss')[0]?.entityId).toBe('t1');
+    });
+
+    it('should pick up renamed members after rebuild', async () => {
+      const topics = [createTopic('t1', 'Generic Topic', {
+        raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] }
+      })];
+      await service.buildIndex(createDatastore({
+        topics,
+        members: [createMember('m1', 'Anna Müller')]
+      }));
+      expect(service.search('Müller').length).toBeGreaterThan(0);
+
+      await service.buildIndex(createDatastore({
+        topics,
+        members: [createMember('m1', 'Anna Schneider')]
+      }));
+      expect(service.search('Schneider')[0]?.entityId).toBe('t1');
+      expect(service.search('Müller')).toEqual([]);
+    });
+
     it('should respect limit parameter', async () => {
       const ds = createDatastore({
         topics: [
@@ -352,6 +440,98 @@ describe('SearchEngineService', () => {
       expect(hit.entityId).toBe('test-uuid');
       expect(typeof hit.score).toBe('number');
     });
+
+    it('should warn about slow searches', async () => {
+      await service.buildIndex(createDatastore({ topics: [createTopic('t1', 'Urlaubsantrag')] }));
+      const warnSpy = vi.spyOn(console, 'warn').mockImplementation(() => undefined);
+      const nowSpy = vi.spyOn(performance, 'now').mockReturnValueOnce(0).mockReturnValueOnce(500);
+
+      service.search('Urlaub');
+
+      expect(warnSpy).toHaveBeenCalledWith(
+        expect.stringContaining('Search took 500 ms'),
+        expect.objectContaining({ query: 'Urlaub', hits: 1 })
+      );
+      nowSpy.mockRestore();
+      warnSpy.mockRestore();
+    });
+
+    it('should not warn about fast searches', async () => {
+      await service.buildIndex(createDatastore({ topics: [createTopic('t1', 'Urlaubsantrag')] }));
+      const warnSpy = vi.spyOn(console, 'warn').mockImplementation(() => undefined);
+      const nowSpy = vi.spyOn(performance, 'now').mockReturnValueOnce(0).mockReturnValueOnce(5);
+
+      service.search('Urlaub');
+
+      expect(warnSpy).not.toHaveBeenCalled();
+      nowSpy.mockRestore();
+      warnSpy.mockRestore();
+    });
+  });
+
+  describe('advanced query syntax', () => {
+    beforeEach(async () => {
+      await service.buildIndex(createDatastore({
+        topics: [
+          createTopic('t1', 'Password reset for VPN'),
+          createTopic('t2', 'Reset of the mail password'),
+          createTopic('t3', 'VPN access request', { description: 'Describes the password reset flow' }),
+          createTopic('t4', 'Vacation approvals', {
+            raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] }
+          })
+        ],
+        members: [createMember('m1', 'Anna Müller')]
+      }));
+    });
+
+    it('should match quoted phrases only in word order', () => {
+      const ids = service.search('"password reset"').map(r => r.entityId);
+      expect(ids).toContain('t1');
+      expect(ids).toContain('t3');
+      expect(ids).not.toContain('t2');
+    });
+
+    it('should match umlaut phrases like plain terms', async () => {
+      await service.buildIndex(createDatastore({ topics: [createTopic('t1', 'Äpfel und Birnen')] }));
+
+      expect(service.search('"apfel und"').map(r => r.entityId)).toEqual(['t1']);
+      expect(service.search('apfel').map(r => r.entityId)).toEqual(['t1']);
+    });
+
+    it('should restrict field-scoped phrases to that field', () => {
+      const ids = service.search('header:"password reset"').map(r => r.entityId);
+      expect(ids).toEqual(['t1']);
+    });
+
+    it('should exclude documents matching a -term', () => {
+      const ids = service.search('password -vpn').map(r => r.entityId);
+      expect(ids).toEqual(['t2']);
+    });
+
+    it('should require +terms', () => {
+      const ids = service.search('reset +mail').map(r => r.entityId);
+      expect(ids).toEqual(['t2']);
+    });
+
+    it('should support member-scoped queries', () => {
+      const ids = service.search('member:Müller').map(r => r.entityId);
+      expect(ids).toEqual(['t4']);
+    });
+
+    it('should return no results for exclusion-only queries', () => {
+      expect(service.search('-vpn')).toEqual([]);
+    });
+
+    it('should throw SearchQueryError for invalid syntax', () => {
+      expect(() => service.search('password ""')).toThrow(SearchQueryError);
+    });
+
+    it('should return results for incomplete input while typing', () => {
+      expect(service.search('"password reset').length).toBeGreaterThan(0);
+      expect(service.search('VPN - access').map(r => r.entityId)).toContain('t3');
+      expect(service.search('password +').length).toBeGreaterThan(0);
+      expect(service.search('VPN 24"').length).toBeGreaterThan(0);
+    });
   });
 
   describe('Index metadata', () => {
@@ -8,16 +8,27 @@
  *   1. Topic name (highest weight: 100)
  *   2. Topic search keywords (weight: 85)
  *   3. Topic description (weight: 70)
- *   4. Topic notes (weight: 55)
- *   5. Tag name (weight: 40)
- *   6. Tag search keywords (weight: 25)
- *   7. Tag notes/hinweise (weight: 10, lowest)
+ *   4. RACI member names (weight: 60)
+ *   5. Topic notes (weight: 55)
+ *   6. Tag name (weight: 40)
+ *   7. Tag search keywords (weight: 25)
+ *   8. Tag notes/hinweise (weight: 10, lowest)
+ * - Query syntax: "phrases", +required/-excluded terms, field scopes (header:urlaub)
  * - Returns top N results sorted by relevance
  */
 
 import { Injectable, signal, computed } from '@angular/core';
-import { Document } from 'flexsearch';
-import { Datastore, Topic } from '../models';
+import { Charset, Document, Encoder } from 'flexsearch';
+import { Datastore, TeamMember, Topic } from '../models';
+import { buildStopWordSet } from '../../shared/utils/stop-words.utils';
+import { createContentFingerprint } from '../../shared/utils/content-fingerprint.utils';
+import {
+  ParsedSearchQuery,
+  SearchClause,
+  SearchField,
+  containsPhrase,
+  parseSearchQuery
+} from './search-query';
 
 /**
  * Entity kinds that can be searched.
@@ -39,6 +50,8 @@ export interface SearchDocument {
   topicKeywords: string;
   /** Topic description - high weight */
   topicDescription: string;
+  /** Display names of the topic's RACI members - medium weight */
+  raciMemberNames: string;
   /** Topic notes - medium weight */
   topicNotes: string;
   /** Tag names - medium-low weight */
@@ -47,6 +60,8 @@ export interface SearchDocument {
   tagKeywords: string;
   /** Tag notes/hinweise - lowest weight */
   tagNotes: string;
+  /** Content fingerprint for grouping near-identical topics (not indexed) */
+  fingerprint: string;
   /** Index signature for FlexSearch compatibility */
   [key: string]: string;
 }
@@ -59,12 +74,37 @@ const FIELD_WEIGHTS: Record<string, number> = {
   title: 100,           // Topic name - highest priority
   topicKeywords: 85,    // Topic search keywords
   topicDescription: 70, // Topic description
+  raciMemberNames: 60,  // RACI member display names
   topicNotes: 55,       // Topic notes
   tagNames: 40,         // Tag names
   tagKeywords: 25,      // Tag search keywords
   tagNotes: 10          // Tag notes/hinweise - lowest priority
 };
 
+/**
+ * Indexed document fields, in order of decreasing weight.
+ */
+const INDEXED_FIELDS: SearchField[] = [
+  'title',
+  'topicKeywords',
+  'topicDescription',
+  'raciMemberNames',
+  'topicNotes',
+  'tagNames',
+  'tagKeywords',
+  'tagNotes'
+];
+
+/**
+ * Per-field result list as returned by FlexSearch with `enrich: true`.
+ */
+type FieldResult = { field: string; result: Array<{ id: string }> };
+
+/**
+ * Accumulated score per document ID.
+ */
+type ScoreMap = Map<string, { score: number; doc: SearchDocument }>;
+
 /**
  * Search result returned from queries.
  */
@@ -79,6 +119,8 @@ export interface SearchHit {
   title: string;
   /** Original entity ID (UUID) */
   entityId: string;
+  /** Lower-ranked near-identical topics collapsed into this hit */
+  duplicates?: SearchHit[];
 }
 
 /**
@@ -96,6 +138,12 @@ export interface IndexMeta {
 const INDEX_META_KEY = 'search:indexMeta';
 const FLEXSEARCH_VERSION = '0.8.212';
 
+/** Searches slower than this are logged as warnings (ms) */
+const SLOW_SEARCH_THRESHOLD_MS = 100;
+
+/** Index builds slower than this are logged as warnings (ms) */
+const SLOW_INDEX_BUILD_THRESHOLD_MS = 2000;
+
 /**
  * Creates a composite ID for indexed documents.
  */
@@ -138,6 +186,9 @@ export class SearchEngineService {
   
   /** Map of tag ID to tag name for resolving tag references */
   private tagsById = new Map<string, string>();
+
+  /** Map of member ID to member for resolving RACI references */
+  private membersById = new Map<string, TeamMember>();
   
   /** Signal indicating the index version (incremented on rebuild) */
   private indexVersionSignal = signal(0);
@@ -154,24 +205,39 @@ export class SearchEngineService {
    * @returns Promise that resolves when indexing is complete
    */
   async buildIndex(datastore: Datastore): Promise<void> {
+    const startedAt = performance.now();
+
     // Clear existing data
     this.documentsMap.clear();
     this.tagsById.clear();
+    this.membersById.clear();
     
     // Build tag lookup map first (needed for resolving tag references)
     for (const tag of datastore.tags || []) {
       this.tagsById.set(tag.id, tag.name);
     }
+
+    // Build member lookup map (needed for resolving RACI member references).
+    // A renamed member changes the datastore checksum, so the IndexMonitor
+    // rebuilds the index and affected topics pick up the new name.
+    for (const member of datastore.members || []) {
+      this.membersById.set(member.id, member);
+    }
     
+    // Stop words are filtered by the encoder, which is used for indexing and queries
+    const stopWords = buildStopWordSet(datastore.searchStopWords);
+
     // Create new FlexSearch Document index with multiple weighted fields
     this.index = new Document({
       document: {
         id: 'id',
-        index: ['title', 'topicKeywords', 'topicDescription', 'topicNotes', 'tagNames', 'tagKeywords', 'tagNotes'],
+        index: INDEXED_FIELDS,
         store: ['id', 'kind', 'title']
       },
       tokenize: 'forward',
-      encoder: 'LatinExtra',  // Good for German umlauts
+      encoder: stopWords.size > 0
+        ? new Encoder(Charset.LatinExtra, { filter: stopWords })
+        : 'LatinExtra',  // Good for German umlauts
       resolution: 9,
       context: {
         depth: 2,
@@ -188,6 +254,10 @@ export class SearchEngineService {
       this.index.add(doc);
     }
 
+    this.warnIfSlow('Index build', startedAt, SLOW_INDEX_BUILD_THRESHOLD_MS, {
+      documents: documents.length
+    });
+
     // Increment version to notify consumers
     this.indexVersionSignal.update(v => v + 1);
   }
@@ -198,32 +268,140 @@ export class SearchEngineService {
    *   1. Topic name (weight: 100)
    *   2. Topic search keywords (weight: 85)
    *   3. Topic description (weight: 70)
-   *   4. Topic notes (weight: 55)
-   *   5. Tag name (weight: 40)
-   *   6. Tag search keywords (weight: 25)
-   *   7. Tag notes/hinweise (weight: 10)
+   *   4. RACI member names (weight: 60)
+   *   5. Topic notes (weight: 55)
+   *   6. Tag name (weight: 40)
+   *   7. Tag search keywords (weight: 25)
+   *   8. Tag notes/hinweise (weight: 10)
+   * 
+   * Supports quoted phrases, +required/-excluded terms and field-scoped
+   * terms (e.g. `header:"password reset"`), see search-query.ts.
    * 
    * @param query - Search query string
    * @param limit - Maximum number of results to return (default: 10)
+   * @param fields - Fields to search in (default: all). Explicit `field:term`
+   *   clauses are not affected by this restriction.
    * @returns Array of SearchHit sorted by relevance (best first)
+   * @throws SearchQueryError if the query syntax is invalid
    */
-  search(query: string, limit: number = 10): SearchHit[] {
+  search(query: string, limit: number = 10, fields: SearchField[] = []): SearchHit[] {
     if (!this.index || !query || query.trim() === '') {
       return [];
     }
 
+    const startedAt = performance.now();
+    const parsed = parseSearchQuery(query);
+    const searchFields = fields.length > 0 ? fields : INDEXED_FIELDS;
+    const hits = parsed.isAdvanced
+      ? this.searchAdvanced(parsed, limit, searchFields)
+      : this.searchSimple(query, limit, searchFields);
+
+    this.warnIfSlow('Search', startedAt, SLOW_SEARCH_THRESHOLD_MS, { query, limit, fields, hits: hits.length });
+    return hits;
+  }
+
+  /**
+   * Executes a plain query across all fields (fuzzy, weighted).
+   */
+  private searchSimple(query: string, limit: number, fields: SearchField[]): SearchHit[] {
     const normalizedQuery = query.trim();
     
     // Search with enriched results to get document data
     const results = this.index.search(normalizedQuery, {
+      index: fields,
       limit: limit * 3, // Get more results to merge and dedupe
       suggest: true,    // Enable fuzzy suggestions
       enrich: true
-    }) as Array<{ field: string; result: Array<{ id: string }> }>;
+    }) as FieldResult[];
 
     // Merge results from different fields and compute scores based on field weights
-    const scoreMap = new Map<string, { score: number; doc: SearchDocument }>();
+    const scoreMap: ScoreMap = new Map();
+    this.accumulateFieldScores(results, scoreMap);
+
+    return this.toSortedHits(scoreMap, limit);
+  }
+
+  /**
+   * Executes a query that uses phrases, +/- operators or field scopes.
+   * Every clause is searched separately; `should` and `must` clauses contribute
+   * to the score, `must` clauses additionally filter, `mustNot` clauses exclude.
+   * A query consisting only of exclusions returns no results.
+   */
+  private searchAdvanced(parsed: ParsedSearchQuery, limit: number, fields: SearchField[]): SearchHit[] {
+    const scoreMap: ScoreMap = new Map();
+    const requiredSets: Set<string>[] = [];
+    const excludedIds = new Set<string>();
+    let hasPositiveClause = false;
+
+    for (const clause of parsed.clauses) {
+      const results = this.searchClause(clause, fields);
+
+      if (clause.occur === 'mustNot') {
+        for (const fieldResult of results) {
+          fieldResult.result.forEach(item => excludedIds.add(item.id));
+        }
+        continue;
+      }
+
+      hasPositiveClause = true;
+      this.accumulateFieldScores(results, scoreMap);
+
+      if (clause.occur === 'must') {
+        const matched = new Set<string>();
+        for (const fieldResult of results) {
+          fieldResult.result.forEach(item => matched.add(item.id));
+        }
+        requiredSets.push(matched);
+      }
+    }
+
+    if (!hasPositiveClause) {
+      return [];
+    }
+
+    for (const id of Array.from(scoreMap.keys())) {
+      if (excludedIds.has(id) || requiredSets.some(set => !set.has(id))) {
+        scoreMap.delete(id);
+      }
+    }
 
+    return this.toSortedHits(scoreMap, limit);
+  }
+
+  /**
+   * Searches a single clause, restricted to its field if scoped, otherwise
+   * to the given fields.
+   * Exclusion and required clauses disable fuzzy suggestions so that they
+   * only match documents that actually contain the term.
+   * Phrase clauses are verified against the stored field text.
+   */
+  private searchClause(clause: SearchClause, fields: SearchField[]): FieldResult[] {
+    const results = this.index.search(clause.text, {
+      index: clause.field ? [clause.field] : fields,
+      limit: Math.max(this.documentsMap.size, 1), // Filtering needs the complete match set
+      suggest: clause.occur === 'should',
+      enrich: true
+    }) as FieldResult[];
+
+    if (!clause.phrase) {
+      return results;
+    }
+
+    return results
+      .map(fieldResult => ({
+        field: fieldResult.field,
+        result: fieldResult.result.filter(item => {
+          const doc = this.documentsMap.get(item.id);
+          return doc !== undefined && containsPhrase(doc[fieldResult.field] || '', clause.text);
+        })
+      }))
+      .filter(fieldResult => fieldResult.result.length > 0);
+  }
+
+  /**
+   * Merges per-field results into the score map using the field weights.
+   */
+  private accumulateFieldScores(results: FieldResult[], scoreMap: ScoreMap): void {
     for (const fieldResult of results) {
       const field = fieldResult.field;
       // Use field weight from FIELD_WEIGHTS, default to 10 if not found
@@ -250,24 +428,56 @@ export class SearchEngineService {
         }
       }
     }
+  }
 
-    // Convert to SearchHit array and sort by score
-    const hits: SearchHit[] = [];
+  /**
+   * Converts the score map to SearchHits sorted by score (best first).
+   * Hits with the same content fingerprint are collapsed into the best-ranked
+   * one, which lists the others in `duplicates`.
+   */
+  private toSortedHits(scoreMap: ScoreMap, limit: number): SearchHit[] {
+    const hits: Array<{ hit: SearchHit; fingerprint: string }> = [];
     for (const [id, { score, doc }] of scoreMap) {
       const { kind, entityId } = parseDocumentId(id);
       hits.push({
-        id,
-        kind,
-        score,
-        title: doc.title,
-        entityId
+        hit: {
+          id,
+          kind,
+          score,
+          title: doc.title,
+          entityId
+        },
+        fingerprint: doc.fingerprint
       });
     }
 
     // Sort by score descending (best first)
-    hits.sort((a, b) => b.score - a.score);
+    hits.sort((a, b) => b.hit.score - a.hit.score);
+
+    const primaries: SearchHit[] = [];
+    const primaryByFingerprint = new Map<string, SearchHit>();
+    for (const { hit, fingerprint } of hits) {
+      const primary = primaryByFingerprint.get(fingerprint);
+      if (primary) {
+        primary.duplicates = [...(primary.duplicates ?? []), hit];
+      } else {
+        primaryByFingerprint.set(fingerprint, hit);
+        primaries.push(hit);
+      }
+    }
 
-    return hits.slice(0, limit);
+    return primaries.slice(0, limit);
+  }
+
+  /**
+   * Logs a warning if an operation took longer than the threshold, so degrading
+   * search performance on large datastores shows up in the browser console.
+   */
+  private warnIfSlow(operation: string, startedAt: number, thresholdMs: number, details: Record<string, unknown>): void {
+    const durationMs = Math.round(performance.now() - startedAt);
+    if (durationMs > thresholdMs) {
+      console.warn(`[SearchEngine] ${operation} took ${durationMs} ms (threshold ${thresholdMs} ms)`, details);
+    }
   }
 
   /**
@@ -324,20 +534,22 @@ export class SearchEngineService {
    * Creates a SearchDocument from a Topic with separate weighted fields.
    * Fields are organized by search priority:
    *   1. title - Topic name/header (highest weight)
-   *   2. topicKeywords - Topic search keywords
+   *   2. topicKeywords - Topic key and search keywords
    *   3. topicDescription - Topic description
-   *   4. topicNotes - Topic notes
-   *   5. tagNames - Names of linked tags
-   *   6. tagKeywords - Search keywords from linked tags
-   *   7. tagNotes - Notes/hinweise from linked tags (lowest weight)
+   *   4. raciMemberNames - Display names of RACI members
+   *   5. topicNotes - Topic notes
+   *   6. tagNames - Names of linked tags
+   *   7. tagKeywords - Search keywords from linked tags
+   *   8. tagNotes - Notes/hinweise from linked tags (lowest weight)
    */
   private createTopicDocument(topic: Topic, datastore: Datastore): SearchDocument {
     const title = topic.header;
     
     // Topic fields (separate for weighting)
-    const topicKeywords = (topic.searchKeywords || []).join(' ');
+    const topicKeywords = [topic.key, ...(topic.searchKeywords || [])].filter(Boolean).join(' ');
     const topicDescription = topic.description || '';
     const topicNotes = topic.notes || '';
+    const raciMemberNames = this.collectRaciMemberNames(topic).join(' ');
     
     // Tag fields (collected from all linked tags)
     const tagNameParts: string[] = [];
@@ -370,10 +582,44 @@ export class SearchEngineService {
       title,
       topicKeywords,
       topicDescription,
+      raciMemberNames,
       topicNotes,
       tagNames: tagNameParts.join(' '),
       tagKeywords: tagKeywordParts.join(' '),
-      tagNotes: tagNoteParts.join(' ')
+      tagNotes: tagNoteParts.join(' '),
+      fingerprint: createContentFingerprint(topic)
     };
   }
+
+  /**
+   * Resolves the display names of all members referenced in a topic's RACI
+   * (R1-R3, C and I). Unknown member IDs are skipped; each member is listed once.
+   */
+  private collectRaciMemberNames(topic: Topic): string[] {
+    const raci = topic.raci;
+    if (!raci) {
+      return [];
+    }
+
+    const memberIds = [
+      raci.r1MemberId,
+      raci.r2MemberId,
+      raci.r3MemberId,
+      ...(raci.cMemberIds || []),
+      ...(raci.iMemberIds || []),
+      ...(raci.escalationMemberIds || [])
+    ];
+
+    const names: string[] = [];
+    const seen = new Set<string>();
+    for (const memberId of memberIds) {
+      if (!memberId || seen.has(memberId)) continue;
+      seen.add(memberId);
+      const member = this.membersById.get(memberId);
+      if (member) {
+        names.push(member.displayName);
+      }
+    }
+    return names;
+  }
 }
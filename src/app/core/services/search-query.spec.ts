@@ -0,0 +1,133 @@
+import { describe, it, expect } from 'vitest';
+import {
+  parseSearchQuery,
+  SearchQueryError,
+  normalizeForPhraseMatch,
+  containsPhrase,
+  parseSearchFields
+} from './search-query';
+
+describe('parseSearchQuery', () => {
+  it('should parse plain terms as should-clauses', () => {
+    const parsed = parseSearchQuery('urlaub antrag');
+    expect(parsed.isAdvanced).toBe(false);
+    expect(parsed.clauses).toEqual([
+      { text: 'urlaub', phrase: false, field: null, occur: 'should' },
+      { text: 'antrag', phrase: false, field: null, occur: 'should' }
+    ]);
+  });
+
+  it('should parse quoted phrases', () => {
+    const parsed = parseSearchQuery('"password  reset" vpn');
+    expect(parsed.isAdvanced).toBe(true);
+    expect(parsed.clauses[0]).toEqual({ text: 'password reset', phrase: true, field: null, occur: 'should' });
+    expect(parsed.clauses[1].text).toBe('vpn');
+  });
+
+  it('should parse required and excluded terms', () => {
+    const parsed = parseSearchQuery('+urlaub -"alte version"');
+    expect(parsed.clauses).toEqual([
+      { text: 'urlaub', phrase: false, field: null, occur: 'must' },
+      { text: 'alte version', phrase: true, field: null, occur: 'mustNot' }
+    ]);
+  });
+
+  it('should parse field-scoped terms and phrases', () => {
+    const parsed = parseSearchQuery('header:"password reset" +Member:Müller tags:HR');
+    expect(parsed.clauses).toEqual([
+      { text: 'password reset', phrase: true, field: 'title', occur: 'should' },
+      { text: 'Müller', phrase: false, field: 'raciMemberNames', occur: 'must' },
+      { text: 'HR', phrase: false, field: 'tagNames', occur: 'should' }
+    ]);
+  });
+
+  it('should treat unknown field prefixes as plain text', () => {
+    const parsed = parseSearchQuery('Frist:30 http://intranet');
+    expect(parsed.isAdvanced).toBe(false);
+    expect(parsed.clauses.map(c => c.text)).toEqual(['Frist:30', 'http://intranet']);
+    expect(parsed.clauses.every(c => c.field === null)).toBe(true);
+  });
+
+  it('should keep hyphens inside words', () => {
+    const parsed = parseSearchQuery('E-Mail-Postfach');
+    expect(parsed.clauses).toEqual([
+      { text: 'E-Mail-Postfach', phrase: false, field: null, occur: 'should' }
+    ]);
+  });
+
+  it('should keep unterminated quotes as literal characters', () => {
+    const parsed = parseSearchQuery('"password reset');
+    expect(parsed.isAdvanced).toBe(false);
+    expect(parsed.clauses.map(c => c.text)).toEqual(['"password', 'reset']);
+    expect(parsed.clauses.every(c => !c.phrase)).toBe(true);
+  });
+
+  it('should keep quotes inside words as literal characters', () => {
+    const parsed = parseSearchQuery('Monitor 24"');
+    expect(parsed.clauses).toEqual([
+      { text: 'Monitor', phrase: false, field: null, occur: 'should' },
+      { text: '24"', phrase: false, field: null, occur: 'should' }
+    ]);
+  });
+
+  it('should reject empty phrases', () => {
+    expect(() => parseSearchQuery('urlaub ""')).toThrow('Leere Phrase');
+  });
+
+  it('should skip lone operators', () => {
+    expect(parseSearchQuery('IT - Beschaffung')).toEqual({
+      clauses: [
+        { text: 'IT', phrase: false, field: null, occur: 'should' },
+        { text: 'Beschaffung', phrase: false, field: null, occur: 'should' }
+      ],
+      isAdvanced: false
+    });
+    expect(parseSearchQuery('urlaub -').clauses.map(c => c.text)).toEqual(['urlaub']);
+    expect(parseSearchQuery('+ urlaub').clauses).toEqual([
+      { text: 'urlaub', phrase: false, field: null, occur: 'should' }
+    ]);
+  });
+
+  it('should report the error position', () => {
+    try {
+      parseSearchQuery('abc ""');
+      expect.fail('expected SearchQueryError');
+    } catch (error) {
+      expect(error).toBeInstanceOf(SearchQueryError);
+      expect((error as SearchQueryError).position).toBe(4);
+    }
+  });
+});
+
+describe('phrase matching', () => {
+  it('should normalize umlauts, case and punctuation', () => {
+    expect(normalizeForPhraseMatch('Gehalts-Erhöhung,  Müller!')).toBe('gehalts erhohung muller');
+  });
+
+  it('should match phrases at word boundaries', () => {
+    expect(containsPhrase('Reset your password reset link', 'password reset')).toBe(true);
+    expect(containsPhrase('Passwort-Reset für VPN', 'passwort reset')).toBe(true);
+    expect(containsPhrase('reset password', 'password reset')).toBe(false);
+    expect(containsPhrase('preset value', 'set')).toBe(false);
+  });
+
+  it('should fold umlauts like the search encoder', () => {
+    expect(containsPhrase('Äpfel und Birnen', 'apfel und')).toBe(true);
+    expect(containsPhrase('Apfel und Birnen', 'äpfel')).toBe(true);
+  });
+});
+
+describe('parseSearchFields', () => {
+  it('should map aliases to index fields', () => {
+    expect(parseSearchFields('header, Schlagworte')).toEqual(['title', 'topicKeywords']);
+  });
+
+  it('should ignore unknown names and duplicates', () => {
+    expect(parseSearchFields('titel,foo,header')).toEqual(['title']);
+  });
+
+  it('should return an empty list for missing values', () => {
+    expect(parseSearchFields(null)).toEqual([]);
+    expect(parseSearchFields('')).toEqual([]);
+  });
+});
@@ -0,0 +1,219 @@
+/**
+ * Query syntax parser for the search engine.
+ *
+ * Supported syntax:
+ * - Plain terms: `urlaub antrag` (any field, fuzzy)
+ * - Quoted phrases: `"password reset"` (words must appear in this order)
+ * - Required terms: `+urlaub` / `+"password reset"`
+ * - Excluded terms: `-entwurf` / `-"alte version"`
+ * - Field-scoped terms: `header:urlaub`, `tags:"Personal"`, `+member:müller`
+ *
+ * Unknown field prefixes (e.g. `http://...` or `Frist:30`) are treated as plain text.
+ * The parser is lenient with incomplete input, since it runs on every keystroke:
+ * a lone `+`/`-` (e.g. `IT - Beschaffung`) is skipped, and an unclosed or
+ * in-word quote (e.g. `Monitor 24"`) is kept as a literal character.
+ * Only empty phrases are reported as SearchQueryError with a user-facing
+ * (German) message.
+ */
+
+/**
+ * Index fields of a topic search document that can be targeted by a field-scoped query.
+ */
+export type SearchField =
+  | 'title'
+  | 'topicKeywords'
+  | 'topicDescription'
+  | 'raciMemberNames'
+  | 'topicNotes'
+  | 'tagNames'
+  | 'tagKeywords'
+  | 'tagNotes';
+
+/**
+ * How a clause affects matching:
+ * - should: contributes to the score, not required
+ * - must: document is only returned if the clause matches (`+`)
+ * - mustNot: document is dropped if the clause matches (`-`)
+ */
+export type SearchOccur = 'should' | 'must' | 'mustNot';
+
+/**
+ * A single clause of a parsed query.
+ */
+export interface SearchClause {
+  /** Term or phrase text (without quotes and operators) */
+  text: string;
+  /** True if the text was quoted and must match as a phrase */
+  phrase: boolean;
+  /** Field the clause is restricted to, or null for all fields */
+  field: SearchField | null;
+  /** Matching semantics of the clause */
+  occur: SearchOccur;
+}
+
+/**
+ * Result of parsing a query string.
+ */
+export interface ParsedSearchQuery {
+  clauses: SearchClause[];
+  /** True if the query uses any advanced syntax (phrases, operators, fields) */
+  isAdvanced: boolean;
+}
+
+/**
+ * Field names accepted in `field:term` syntax, mapped to index fields.
+ * English and German aliases are accepted (case-insensitive).
+ */
+export const SEARCH_FIELD_ALIASES: Record<string, SearchField> = {
+  header: 'title',
+  title: 'title',
+  titel: 'title',
+  keywords: 'topicKeywords',
+  schlagworte: 'topicKeywords',
+  description: 'topicDescription',
+  beschreibung: 'topicDescription',
+  member: 'raciMemberNames',
+  members: 'raciMemberNames',
+  person: 'raciMemberNames',
+  raci: 'raciMemberNames',
+  notes: 'topicNotes',
+  notizen: 'topicNotes',
+  tag: 'tagNames',
+  tags: 'tagNames',
+  tagkeywords: 'tagKeywords',
+  hinweise: 'tagNotes'
+};
+
+/**
+ * Display labels of the index fields, used for the field selection in the search UI.
+ */
+export const SEARCH_FIELD_LABELS: Record<SearchField, string> = {
+  title: 'Titel',
+  topicKeywords: 'Schlagworte',
+  topicDescription: 'Beschreibung',
+  raciMemberNames: 'Personen',
+  topicNotes: 'Notizen',
+  tagNames: 'Tags',
+  tagKeywords: 'Tag-Schlagworte',
+  tagNotes: 'Tag-Hinweise'
+};
+
+/**
+ * Parses a comma-separated field list such as `header,keywords` (e.g. from the
+ * `in` URL parameter) using the same aliases as the `field:term` syntax.
+ * Unknown names are ignored, duplicates removed.
+ */
+export function parseSearchFields(value: string | null | undefined): SearchField[] {
+  const fields: SearchField[] = [];
+  for (const name of (value ?? '').split(',')) {
+    const field = SEARCH_FIELD_ALIASES[name.trim().toLowerCase()];
+    if (field && !fields.includes(field)) {
+      fields.push(field);
+    }
+  }
+  return fields;
+}
+
+/**
+ * Error thrown for syntactically invalid queries.
+ * The message is meant to be shown to the user.
+ */
+export class SearchQueryError extends Error {
+  constructor(message: string, public readonly position: number) {
+    super(message);
+    this.name = 'SearchQueryError';
+  }
+}
+
+/**
+ * Parses a query string into clauses.
+ *
+ * @param query - Raw query string as typed by the user
+ * @returns Parsed clauses
+ * @throws SearchQueryError if the query is syntactically invalid
+ */
+export function parseSearchQuery(query: string): ParsedSearchQuery {
+  const clauses: SearchClause[] = [];
+  let isAdvanced = false;
+  let pos = 0;
+
+  while (pos < query.length) {
+    // Skip whitespace between clauses
+    if (/\s/.test(query[pos])) {
+      pos++;
+      continue;
+    }
+
+    let occur: SearchOccur = 'should';
+    if (query[pos] === '+' || query[pos] === '-') {
+      pos++;
+      // A lone operator (e.g. the dash in `IT - Beschaffung`) is skipped
+      if (pos >= query.length || /\s/.test(query[pos])) {
+        continue;
+      }
+      occur = query[pos - 1] === '+' ? 'must' : 'mustNot';
+      isAdvanced = true;
+    }
+
+    // Optional field prefix: `name:` directly followed by a term or phrase
+    let field: SearchField | null = null;
+    const fieldMatch = /^([a-zA-Z]+):(?=\S)/.exec(query.substring(pos));
+    if (fieldMatch) {
+      const mapped = SEARCH_FIELD_ALIASES[fieldMatch[1].toLowerCase()];
+      if (mapped) {
+        field = mapped;
+        pos += fieldMatch[0].length;
+        isAdvanced = true;
+      }
+    }
+
+    // An unclosed quote falls through and is read as part of a plain term
+    const closing = query[pos] === '"' ? query.indexOf('"', pos + 1) : -1;
+    if (closing !== -1) {
+      const text = query.substring(pos + 1, closing).trim().replace(/\s+/g, ' ');
+      if (text === '') {
+        throw new SearchQueryError('Leere Phrase "" ist nicht erlaubt.', pos);
+      }
+      clauses.push({ text, phrase: true, field, occur });
+      isAdvanced = true;
+      pos = closing + 1;
+      continue;
+    }
+
+    // Plain term: read until whitespace, quotes inside are kept as literal characters
+    let end = pos;
+    while (end < query.length && !/\s/.test(query[end])) {
+      end++;
+    }
+    clauses.push({ text: query.substring(pos, end), phrase: false, field, occur });
+    pos = end;
+  }
+
+  return { clauses, isAdvanced };
+}
+
+/**
+ * Normalizes text for phrase comparison: lowercase, diacritics stripped (ä → a, like
+ * the search encoder), punctuation and whitespace collapsed to single spaces.
+ */
+export function normalizeForPhraseMatch(text: string): string {
+  return text
+    .toLowerCase()
+    .normalize('NFD')
+    .replace(/[\u0300-\u036f]/g, '')
+    .replace(/[^\p{L}\p{N}]+/gu, ' ')
+    .trim();
+}
+
+/**
+ * Checks whether `text` contains `phrase` starting at a word boundary.
+ * Both arguments are normalized with normalizeForPhraseMatch. The last word of the
+ * phrase may be a prefix (consistent with the forward tokenization of the index).
+ */
+export function containsPhrase(text: string, phrase: string): boolean {
+  const normalizedPhrase = normalizeForPhraseMatch(phrase);
+  if (normalizedPhrase === '') {
+    return false;
+  }
+  return (' ' + normalizeForPhraseMatch(text)).includes(' ' + normalizedPhrase);
+}
@@ -0,0 +1,69 @@
+import { describe, it, expect, beforeEach } from 'vitest';
+import { TopicDraftService } from './topic-draft.service';
+import { Topic } from '../models';
+
+// Mock localStorage
+const localStorageMock = (() => {
+  let store: Record<string, string> = {};
+  return {
+    getItem: (key: string) => store[key] || null,
+    setItem: (key: string, value: string) => { store[key] = value; },
+    removeItem: (key: string) => { delete store[key]; },
+    clear: () => { store = {}; }
+  };
+})();
+Object.defineProperty(globalThis, 'localStorage', { value: localStorageMock });
+
+describe('TopicDraftService', () => {
+  let service: TopicDraftService;
+
+  const createTopic = (id: string, header: string): Topic => ({
+    id,
+    header,
+    validity: { alwaysValid: true },
+    raci: { r1MemberId: 'member-1', cMemberIds: [], iMemberIds: [] },
+    updatedAt: '2024-01-01T00:00:00Z'
+  });
+
+  beforeEach(() => {
+    localStorageMock.clear();
+    localStorageMock.setItem('currentMemberId', 'member-1');
+    service = new TopicDraftService();
+  });
+
+  it('should return null when no draft exists', () => {
+    expect(service.getDraft('t1')).toBeNull();
+  });
+
+  it('should store and restore a draft', () => {
+    service.saveDraft('t1', createTopic('t1', 'Edited header'));
+
+    const draft = service.getDraft('t1');
+    expect(draft).not.toBeNull();
+    expect(draft!.topicId).toBe('t1');
+    expect(draft!.topic.header).toBe('Edited header');
+    expect(draft!.savedAt).toBeTruthy();
+  });
+
+  it('should keep drafts for new topics separate', () => {
+    service.saveDraft('', createTopic('', 'New topic'));
+    service.saveDraft('t1', createTopic('t1', 'Existing topic'));
+
+    expect(service.getDraft('')!.topic.header).toBe('New topic');
+    expect(service.getDraft('t1')!.topic.header).toBe('Existing topic');
+  });
+
+  it('should keep drafts separate per user', () => {
+    service.saveDraft('t1', createTopic('t1', 'Draft of member 1'));
+
+    localStorageMock.setItem('currentMemberId', 'member-2');
+    expect(service.getDraft('t1')).toBeNull();
+  });
+
+  it('should discard a draft', () => {
+    service.saveDraft('t1', createTopic('t1', 'Edited header'));
+    service.discardDraft('t1');
+
+    expect(service.getDraft('t1')).toBeNull();
+  });
+});
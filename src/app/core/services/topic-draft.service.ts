@@ -0,0 +1,73 @@
+import { Injectable } from '@angular/core';
+import { Topic } from '../models';
+
+/**
+ * Unsaved edit state of a topic, kept per user in localStorage.
+ */
+export interface TopicDraft {
+  /** ID of the edited topic, empty for a topic that has not been created yet */
+  topicId: string;
+  /** Edited topic state as shown in the edit dialog */
+  topic: Topic;
+  /** ISO timestamp when the draft was stored */
+  savedAt: string;
+}
+
+const DRAFT_KEY_PREFIX = 'topicDraft:';
+
+/**
+ * Stores half-finished topic edits so they survive closing the dialog or
+ * reloading the browser. Drafts are local to the browser and the current
+ * user; they never touch the shared datastore and are discarded on save.
+ */
+@Injectable({
+  providedIn: 'root'
+})
+export class TopicDraftService {
+  /**
+   * Stores a draft for a topic, replacing any previous draft.
+   *
+   * @param topicId - ID of the edited topic, empty for a new topic
+   * @param topic - Current edit state
+   */
+  saveDraft(topicId: string, topic: Topic): void {
+    const draft: TopicDraft = {
+      topicId,
+      topic,
+      savedAt: new Date().toISOString()
+    };
+    try {
+      localStorage.setItem(this.storageKey(topicId), JSON.stringify(draft));
+    } catch (error) {
+      // Storage full or unavailable - a lost draft is not critical
+      console.warn('Could not store topic draft:', error);
+    }
+  }
+
+  /**
+   * Returns the stored draft for a topic, or null if there is none.
+   */
+  getDraft(topicId: string): TopicDraft | null {
+    try {
+      const stored = localStorage.getItem(this.storageKey(topicId));
+      if (stored) {
+        return JSON.parse(stored) as TopicDraft;
+      }
+    } catch {
+      // Ignore parse errors
+    }
+    return null;
+  }
+
+  /**
+   * Removes the stored draft for a topic.
+   */
+  discardDraft(topicId: string): void {
+    localStorage.removeItem(this.storageKey(topicId));
+  }
+
+  private storageKey(topicId: string): string {
+    const memberId = localStorage.getItem('currentMemberId') || 'anonymous';
+    return `${DRAFT_KEY_PREFIX}${memberId}:${topicId || 'new'}`;
+  }
+}
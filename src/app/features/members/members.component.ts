@@ -19,12 +19,18 @@ import { Select } from 'primeng/select';
 import { ColorPicker } from 'primeng/colorpicker';
 import { Checkbox } from 'primeng/checkbox';
 import { Tooltip } from 'primeng/tooltip';
+import { DatePicker } from 'primeng/datepicker';
 import { MessageService, ConfirmationService } from 'primeng/api';
 import { Subscription } from 'rxjs';
 import { BackendService } from '../../core/services/backend.service';
 import { LoadConfigService } from '../../core/services/load-config.service';
-import { TeamMember, Topic, Datastore, LoadConfig } from '../../core/models';
+import { TeamMember, Topic, Datastore, LoadConfig, AbsenceType, MemberAbsence } from '../../core/models';
 import { PageWrapperComponent } from '../../shared/components';
+import { sortLocale } from '../../shared/utils/sort.utils';
+import { pickDistinctColor } from '../../shared/utils/color.utils';
+import { toVCard, toVCards } from '../../shared/utils/vcard.utils';
+import { formatValidityDate, getValidityStatus, toValidityDateString } from '../../shared/utils/validity.utils';
+import { ABSENCE_TYPE_LABELS, getCurrentAbsence, isValidAbsence } from '../../shared/utils/absence.utils';
 
 interface TopicAssignment {
   topic: Topic;
@@ -59,6 +65,7 @@ interface TopicAssignment {
     ColorPicker,
     Checkbox,
     Tooltip,
+    DatePicker,
     PageWrapperComponent
   ],
   providers: [MessageService, ConfirmationService],
@@ -100,6 +107,15 @@ export class MembersComponent implements OnInit, OnDestroy {
     { label: 'Inaktiv', value: false }
   ];
 
+  // Absence entry in the edit dialog
+  absenceTypeOptions = (Object.keys(ABSENCE_TYPE_LABELS) as AbsenceType[]).map(type => ({
+    label: ABSENCE_TYPE_LABELS[type],
+    value: type
+  }));
+  newAbsenceFrom: Date | null = null;
+  newAbsenceTo: Date | null = null;
+  newAbsenceType: AbsenceType = 'vacation';
+
   // Load config related properties
   loadConfig: LoadConfig | null = null;
   memberPartTimePercent: number = 100; // Display as percentage (1-100)
@@ -178,7 +194,7 @@ export class MembersComponent implements OnInit, OnDestroy {
 
     const tagSet = new Set<string>();
     datastore.members.forEach(m => m.tags?.forEach(tag => tagSet.add(tag)));
-    this.allTags = Array.from(tagSet).sort();
+    this.allTags = sortLocale(tagSet);
   }
 
   searchTags(event: { query: string }): void {
@@ -220,6 +236,7 @@ export class MembersComponent implements OnInit, OnDestroy {
 
   openNewDialog(): void {
     this.member = this.createEmptyMember();
+    this.resetAbsenceInput();
     this.submitted = false;
     this.editMode = false;
     // Reset load config fields for new member
@@ -232,8 +249,10 @@ export class MembersComponent implements OnInit, OnDestroy {
   editMember(member: TeamMember): void {
     this.member = { 
       ...member,
-      tags: [...(member.tags || [])]
+      tags: [...(member.tags || [])],
+      absences: [...(member.absences || [])]
     };
+    this.resetAbsenceInput();
     this.submitted = false;
     this.editMode = true;
     // Load load config values from member data
@@ -286,6 +305,9 @@ export class MembersComponent implements OnInit, OnDestroy {
       } else {
         this.member.id = this.backend.generateUUID();
         this.member.updatedAt = new Date().toISOString();
+        if (!this.member.color) {
+          this.member.color = pickDistinctColor(this.member.displayName, this.members.map(m => m.color));
+        }
         success = await this.backend.addMember(this.member);
       }
 
@@ -426,47 +448,119 @@ export class MembersComponent implements OnInit, OnDestroy {
   }
 
   getValidityBadge(topic: Topic): string {
-    if (topic.validity.alwaysValid) {
-      return 'Immer gültig';
+    switch (getValidityStatus(topic.validity)) {
+      case 'always':
+        return 'Immer gültig';
+      case 'future':
+        return `Ab ${formatValidityDate(topic.validity.validFrom)}`;
+      case 'expired':
+        return 'Abgelaufen';
+      default:
+        return topic.validity.validFrom && topic.validity.validTo
+          ? `Bis ${formatValidityDate(topic.validity.validTo)}`
+          : 'Gültig';
     }
+  }
 
-    const now = new Date();
-    const validFrom = topic.validity.validFrom ? new Date(topic.validity.validFrom) : null;
-    const validTo = topic.validity.validTo ? new Date(topic.validity.validTo) : null;
-
-    if (validFrom && now < validFrom) {
-      return `Ab ${this.formatDateShort(topic.validity.validFrom!)}`;
+  getValiditySeverity(topic: Topic): 'success' | 'info' | 'warn' | 'danger' | 'secondary' | 'contrast' {
+    switch (getValidityStatus(topic.validity)) {
+      case 'always':
+        return 'success';
+      case 'future':
+        return 'warn';
+      case 'expired':
+        return 'danger';
+      default:
+        return 'info';
     }
+  }
 
-    if (validTo && now > validTo) {
-      return 'Abgelaufen';
+  /**
+   * Adds the absence entered in the dialog to the edited member.
+   */
+  addAbsence(): void {
+    const absence = this.buildNewAbsence();
+    if (!isValidAbsence(absence)) {
+      return;
     }
+    this.member.absences = [...(this.member.absences || []), absence]
+      .sort((a, b) => a.from.localeCompare(b.from));
+    this.resetAbsenceInput();
+  }
 
-    if (validFrom && validTo) {
-      return `Bis ${this.formatDateShort(topic.validity.validTo!)}`;
-    }
+  canAddAbsence(): boolean {
+    return isValidAbsence(this.buildNewAbsence());
+  }
 
-    return 'Gültig';
+  /**
+   * Builds an absence from the dialog input. Without an end date it covers a single day.
+   */
+  private buildNewAbsence(): MemberAbsence {
+    return {
+      from: toValidityDateString(this.newAbsenceFrom) ?? '',
+      to: toValidityDateString(this.newAbsenceTo ?? this.newAbsenceFrom) ?? '',
+      type: this.newAbsenceType
+    };
   }
 
-  getValiditySeverity(topic: Topic): 'success' | 'info' | 'warn' | 'danger' | 'secondary' | 'contrast' {
-    if (topic.validity.alwaysValid) {
-      return 'success';
-    }
+  removeAbsence(absence: MemberAbsence): void {
+    this.member.absences = (this.member.absences || []).filter(a => a !== absence);
+  }
 
-    const now = new Date();
-    const validFrom = topic.validity.validFrom ? new Date(topic.validity.validFrom) : null;
-    const validTo = topic.validity.validTo ? new Date(topic.validity.validTo) : null;
+  private resetAbsenceInput(): void {
+    this.newAbsenceFrom = null;
+    this.newAbsenceTo = null;
+    this.newAbsenceType = 'vacation';
+  }
 
-    if (validFrom && now < validFrom) {
-      return 'warn';
-    }
+  formatAbsence(absence: MemberAbsence): string {
+    const range = absence.from === absence.to
+      ? formatValidityDate(absence.from)
+      : `${formatValidityDate(absence.from)} – ${formatValidityDate(absence.to)}`;
+    return `${ABSENCE_TYPE_LABELS[absence.type]}: ${range}`;
+  }
 
-    if (validTo && now > validTo) {
-      return 'danger';
-    }
+  /**
+   * Returns a description of the current absence of a member, or null if present.
+   */
+  getCurrentAbsenceLabel(member: TeamMember): string | null {
+    const absence = getCurrentAbsence(member);
+    return absence ? this.formatAbsence(absence) : null;
+  }
+
+  /**
+   * Downloads a vCard for a single member.
+   */
+  exportVCard(member: TeamMember): void {
+    this.downloadVCardFile(toVCard(member), `${member.displayName.trim() || member.id}.vcf`);
+  }
+
+  /**
+   * Downloads one .vcf file with vCards of all active members.
+   */
+  exportVCards(): void {
+    const activeMembers = this.members.filter(m => m.active);
+    this.downloadVCardFile(toVCards(activeMembers), `teammitglieder-${new Date().toISOString().split('T')[0]}.vcf`);
+    this.messageService.add({
+      severity: 'success',
+      summary: 'Export erstellt',
+      detail: `${activeMembers.length} vCards exportiert`
+    });
+  }
+
+  private downloadVCardFile(content: string, fileName: string): void {
+    const blob = new Blob([content], { type: 'text/vcard;charset=utf-8' });
+    const url = URL.createObjectURL(blob);
+    const link = document.createElement('a');
+    link.href = url;
+    link.download = fileName.replace(/[\\/:*?"<>|]/g, '_');
+    link.click();
+    URL.revokeObjectURL(url);
+  }
 
-    return 'info';
+  getMemberName(memberId: string): string {
+    const member = this.members.find(m => m.id === memberId);
+    return member?.displayName || 'Unbekannt';
   }
 
   formatDate(isoString: string): string {
@@ -481,12 +575,6 @@ export class MembersComponent implements OnInit, OnDestroy {
     });
   }
 
-  formatDateShort(isoString: string): string {
-    if (!isoString) return '';
-    const date = new Date(isoString);
-    return date.toLocaleDateString('de-DE');
-  }
-
   /**
    * Get the default base load from enabled components.
    */
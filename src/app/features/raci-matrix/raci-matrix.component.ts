@@ -6,6 +6,7 @@ import { Tag } from 'primeng/tag';
 import { Tooltip } from 'primeng/tooltip';
 import { BackendService } from '../../core/services/backend.service';
 import { Topic, TeamMember, Datastore } from '../../core/models';
+import { compareLocale } from '../../shared/utils/sort.utils';
 
 interface MatrixCell {
   roles: string[]; // Array of role abbreviations (R1, R2, R3, C, I)
@@ -52,7 +53,7 @@ export class RaciMatrixComponent implements OnInit, OnDestroy {
   activeMembers = computed<TeamMember[]>(() => 
     this.members()
       .filter(m => m.active)
-      .sort((a, b) => a.displayName.localeCompare(b.displayName))
+      .sort((a, b) => compareLocale(a.displayName, b.displayName))
   );
 
   // Computed: matrix data with rows and cells
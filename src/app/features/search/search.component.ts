@@ -12,15 +12,22 @@ import { Toast } from 'primeng/toast';
 import { Divider } from 'primeng/divider';
 import { Rating } from 'primeng/rating';
 import { Tooltip } from 'primeng/tooltip';
+import { MultiSelect } from 'primeng/multiselect';
 import { MessageService } from 'primeng/api';
+import { ActivatedRoute } from '@angular/router';
 import { Subscription } from 'rxjs';
 import { HotkeysService } from '@ngneat/hotkeys';
 import { BackendService } from '../../core/services/backend.service';
 import { SearchEngineService, SearchHit } from '../../core/services/search-engine.service';
 import { IndexMonitorService } from '../../core/services/index-monitor.service';
+import { SEARCH_FIELD_LABELS, SearchField, SearchQueryError, parseSearchFields } from '../../core/services/search-query';
 import { FileConnectionService } from '../../core/services/file-connection.service';
 import { Datastore, Topic, Tag as TagModel, TShirtSize } from '../../core/models';
 import { getPriorityStars, getSizeSeverity } from '../../shared/utils/topic-display.utils';
+import { formatValidityDate, getValidityStatus } from '../../shared/utils/validity.utils';
+import { findTopicByKey } from '../../shared/utils/topic-key.utils';
+import { findTopicBySlug } from '../../shared/utils/slug.utils';
+import { ResponsibleResolution, ResponsibleRole, describeResponsibleResolution, formatResponsibleRole, resolveResponsibleNow } from '../../shared/utils/responsible.utils';
 
 /**
  * Extended search result with resolved topic data.
@@ -33,7 +40,7 @@ interface DisplaySearchResult {
 @Component({
   selector: 'app-search',
   standalone: true,
-  imports: [CommonModule, FormsModule, InputText, Card, Tag, Button, Message, ProgressSpinner, Dialog, Toast, Divider, Rating, Tooltip],
+  imports: [CommonModule, FormsModule, InputText, Card, Tag, Button, Message, ProgressSpinner, Dialog, Toast, Divider, Rating, Tooltip, MultiSelect],
   providers: [MessageService],
   templateUrl: './search.component.html',
   styleUrl: './search.component.scss',
@@ -44,6 +51,12 @@ export class SearchComponent implements OnInit, OnDestroy, AfterViewInit {
 
   searchQuery: string = '';
   searchResults: DisplaySearchResult[] = [];
+  /** User-facing message for an invalid query syntax, empty if the query is valid */
+  searchQueryError = '';
+  /** Fields the search is restricted to, empty for all fields (`?in=header,keywords`) */
+  searchFields: SearchField[] = [];
+  searchFieldOptions = (Object.keys(SEARCH_FIELD_LABELS) as SearchField[])
+    .map(field => ({ label: SEARCH_FIELD_LABELS[field], value: field }));
   selectedIndex: number = -1;
   isConnected = false;
   isConnecting = false;
@@ -63,6 +76,8 @@ export class SearchComponent implements OnInit, OnDestroy, AfterViewInit {
   
   private subscriptions: Subscription[] = [];
   private currentDatastore: Datastore | null = null;
+  /** Topic reference from the URL (?key=RACI-142 or ?slug=...), opened once the datastore is available */
+  private pendingTopicLink: { key: string | null; slug: string | null } | null = null;
 
   constructor(
     private backend: BackendService,
@@ -72,7 +87,8 @@ export class SearchComponent implements OnInit, OnDestroy, AfterViewInit {
     private cdr: ChangeDetectorRef,
     private ngZone: NgZone,
     private hotkeys: HotkeysService,
-    private messageService: MessageService
+    private messageService: MessageService,
+    private route: ActivatedRoute
   ) {
     // React to index version changes (triggers when index is rebuilt)
     // Use allowSignalWrites to prevent NG0100 error
@@ -125,6 +141,24 @@ export class SearchComponent implements OnInit, OnDestroy, AfterViewInit {
       this.backend.datastore$.subscribe(datastore => {
         if (datastore) {
           this.currentDatastore = datastore;
+          this.openPendingTopicLink();
+        }
+      })
+    );
+
+    // Deep link to a topic by its key or slug, e.g. /search?key=RACI-142
+    this.subscriptions.push(
+      this.route.queryParamMap.subscribe(params => {
+        const key = params.get('key');
+        const slug = params.get('slug');
+        this.pendingTopicLink = key || slug ? { key, slug } : null;
+        this.openPendingTopicLink();
+
+        const fields = parseSearchFields(params.get('in'));
+        if (fields.join(',') !== this.searchFields.join(',')) {
+          this.searchFields = fields;
+          this.performSearch();
+          this.cdr.markForCheck();
         }
       })
     );
@@ -317,6 +351,7 @@ export class SearchComponent implements OnInit, OnDestroy, AfterViewInit {
   }
 
   performSearch(): void {
+    this.searchQueryError = '';
     if (!this.searchQuery || this.searchQuery.trim() === '') {
       this.searchResults = [];
       this.selectedIndex = -1;
@@ -324,7 +359,18 @@ export class SearchComponent implements OnInit, OnDestroy, AfterViewInit {
     }
 
     // Search and get top 10 results
-    const hits = this.searchEngine.search(this.searchQuery, 10);
+    let hits: SearchHit[];
+    try {
+      hits = this.searchEngine.search(this.searchQuery, 10, this.searchFields);
+    } catch (error) {
+      if (!(error instanceof SearchQueryError)) {
+        throw error;
+      }
+      this.searchQueryError = error.message;
+      this.searchResults = [];
+      this.selectedIndex = -1;
+      return;
+    }
     console.debug('[SearchComponent] Search results for', this.searchQuery, ':', hits.length, 'hits');
     
     // Resolve topics for display
@@ -332,6 +378,13 @@ export class SearchComponent implements OnInit, OnDestroy, AfterViewInit {
     this.selectedIndex = this.searchResults.length > 0 ? 0 : -1;
   }
 
+  /**
+   * Titles of the near-identical topics collapsed into a hit.
+   */
+  getDuplicateTitles(hit: SearchHit): string {
+    return (hit.duplicates ?? []).map(duplicate => duplicate.title).join(', ');
+  }
+
   /**
    * Resolves a SearchHit to include the full topic data.
    */
@@ -372,6 +425,30 @@ export class SearchComponent implements OnInit, OnDestroy, AfterViewInit {
     this.detailDialogVisible = true;
   }
 
+  /**
+   * Opens the topic referenced by the key or slug query parameter, if it can be resolved.
+   * Slugs also resolve through previous slugs of renamed topics.
+   */
+  private openPendingTopicLink(): void {
+    const link = this.pendingTopicLink;
+    if (!link || !this.currentDatastore) {
+      return;
+    }
+    const topics = this.currentDatastore.topics;
+    const topic = link.key ? findTopicByKey(topics, link.key) : findTopicBySlug(topics, link.slug ?? '');
+    if (!topic) {
+      this.messageService.add({
+        severity: 'warn',
+        summary: 'Nicht gefunden',
+        detail: `Kein Thema für ${link.key ?? link.slug}`
+      });
+    } else {
+      this.openDetailDialog(topic);
+    }
+    this.pendingTopicLink = null;
+    this.cdr.markForCheck();
+  }
+
   /**
    * Close the detail dialog and refocus search
    */
@@ -402,6 +479,21 @@ export class SearchComponent implements OnInit, OnDestroy, AfterViewInit {
     return member?.displayName || 'Unbekannt';
   }
 
+  /**
+   * Resolves who to contact for a topic right now (R1 → R2 → R3, skipping inactive and absent members).
+   */
+  getResponsibleNow(topic: Topic): ResponsibleResolution {
+    return resolveResponsibleNow(topic, this.currentDatastore?.members || []);
+  }
+
+  describeResponsibleNow(resolution: ResponsibleResolution): string {
+    return describeResponsibleResolution(resolution);
+  }
+
+  formatResponsibleRole(role: ResponsibleRole): string {
+    return formatResponsibleRole(role);
+  }
+
   getMemberEmail(memberId: string): string {
     if (!this.currentDatastore) {
       return '';
@@ -434,51 +526,36 @@ export class SearchComponent implements OnInit, OnDestroy, AfterViewInit {
   }
 
   getValidityBadge(topic: Topic): string {
-    if (topic.validity.alwaysValid) {
-      return 'Immer gültig';
-    }
-
-    const now = new Date();
-    const validFrom = topic.validity.validFrom ? new Date(topic.validity.validFrom) : null;
-    const validTo = topic.validity.validTo ? new Date(topic.validity.validTo) : null;
-
-    if (validFrom && now < validFrom) {
-      return `Ab ${validFrom.toLocaleDateString('de-DE')}`;
-    }
-
-    if (validTo && now > validTo) {
-      return 'Abgelaufen';
-    }
-
-    if (validFrom && !validTo) {
-      return `Gültig ab ${validFrom.toLocaleDateString('de-DE')}`;
-    }
-
-    if (validFrom && validTo) {
-      return `Gültig bis ${validTo.toLocaleDateString('de-DE')}`;
+    const { validFrom, validTo } = topic.validity;
+    switch (getValidityStatus(topic.validity)) {
+      case 'always':
+        return 'Immer gültig';
+      case 'future':
+        return `Ab ${formatValidityDate(validFrom)}`;
+      case 'expired':
+        return 'Abgelaufen';
+      default:
+        if (validFrom && !validTo) {
+          return `Gültig ab ${formatValidityDate(validFrom)}`;
+        }
+        if (validFrom && validTo) {
+          return `Gültig bis ${formatValidityDate(validTo)}`;
+        }
+        return 'Gültig';
     }
-
-    return 'Gültig';
   }
 
   getValiditySeverity(topic: Topic): 'success' | 'info' | 'warn' | 'danger' | 'secondary' | 'contrast' {
-    if (topic.validity.alwaysValid) {
-      return 'success';
+    switch (getValidityStatus(topic.validity)) {
+      case 'always':
+        return 'success';
+      case 'future':
+        return 'warn';
+      case 'expired':
+        return 'danger';
+      default:
+        return 'info';
     }
-
-    const now = new Date();
-    const validFrom = topic.validity.validFrom ? new Date(topic.validity.validFrom) : null;
-    const validTo = topic.validity.validTo ? new Date(topic.validity.validTo) : null;
-
-    if (validFrom && now < validFrom) {
-      return 'warn';
-    }
-
-    if (validTo && now > validTo) {
-      return 'danger';
-    }
-
-    return 'info';
   }
 
   /** Focus delay in ms after dialog close */
@@ -5,6 +5,12 @@ import { toSignal } from '@angular/core/rxjs-interop';
 import { Card } from 'primeng/card';
 import { Button } from 'primeng/button';
 import { SelectButton } from 'primeng/selectbutton';
+import { MultiSelect } from 'primeng/multiselect';
+import { Select } from 'primeng/select';
+import { InputText } from 'primeng/inputtext';
+import { InputNumber } from 'primeng/inputnumber';
+import { ToggleSwitch } from 'primeng/toggleswitch';
+import { Textarea } from 'primeng/textarea';
 import { Divider } from 'primeng/divider';
 import { Tag } from 'primeng/tag';
 import { Message } from 'primeng/message';
@@ -15,7 +21,14 @@ import { BackendService } from '../../core/services/backend.service';
 import { FileConnectionService } from '../../core/services/file-connection.service';
 import { CacheService, CacheState } from '../../core/services/cache.service';
 import { PersistenceService } from '../../core/services/persistence.service';
-import { Datastore } from '../../core/models';
+import { createPartialExport, ExportMemberScope } from '../../core/services/datastore-export';
+import { AutoRule, AutoRuleField, Datastore, ReferencePolicy } from '../../core/models';
+import { compareLocale, sortLocale } from '../../shared/utils/sort.utils';
+import { applyRaciDefaults } from '../../shared/utils/raci-defaults.utils';
+import { isValidSharedPath, normalizeSharedPath } from '../../shared/utils/file-path.utils';
+import { isValidFileNumberPattern } from '../../shared/utils/file-number.utils';
+import { parseStopWords } from '../../shared/utils/stop-words.utils';
+import { AUTO_RULE_FIELD_LABELS, applyAutoRules } from '../../shared/utils/auto-rules.utils';
 import { PageWrapperComponent } from '../../shared/components';
 
 type BackendType = 'filesystem' | 'rest';
@@ -27,7 +40,7 @@ interface BackendOption {
 
 @Component({
   selector: 'app-settings',
-  imports: [CommonModule, FormsModule, Card, Button, SelectButton, Divider, Tag, Message, ConfirmDialog, PageWrapperComponent],
+  imports: [CommonModule, FormsModule, Card, Button, SelectButton, MultiSelect, Select, InputText, InputNumber, ToggleSwitch, Textarea, Divider, Tag, Message, ConfirmDialog, PageWrapperComponent],
   providers: [ConfirmationService],
   templateUrl: './settings.component.html',
   styleUrl: './settings.component.scss',
@@ -63,6 +76,34 @@ export class SettingsComponent implements OnInit, OnDestroy {
   browserInfo = '';
   hasFileSystemAPI = false;
 
+  exportTagOptions: string[] = [];
+  exportTagNames: string[] = [];
+  exportMemberScope: ExportMemberScope = 'referenced';
+  exportMemberOptions: { label: string; value: ExportMemberScope }[] = [
+    { label: 'Nur beteiligte Mitglieder', value: 'referenced' },
+    { label: 'Alle Mitglieder', value: 'all' }
+  ];
+
+  defaultMemberOptions: { id: string; displayName: string }[] = [];
+  defaultCMemberIds: string[] = [];
+  defaultIMemberIds: string[] = [];
+
+  autoRules: AutoRule[] = [];
+  autoRuleFieldOptions = (Object.keys(AUTO_RULE_FIELD_LABELS) as AutoRuleField[])
+    .map(value => ({ label: AUTO_RULE_FIELD_LABELS[value], value }));
+  newRuleName = '';
+  newRuleField: AutoRuleField = 'header';
+  newRuleContains = '';
+  newRuleTags: string[] = [];
+  newRulePriority: number | null = null;
+
+  sharedPathPrefixesText = '';
+  fileNumberPattern = '';
+  fileNumberUppercase = false;
+  fileNumberUnique = false;
+  useGermanStopWords = false;
+  customStopWordsText = '';
+
   // Cache state signals
   private readonly cacheState = toSignal(this.cache.cacheState$, {
     initialValue: { datastore: null, isDirty: false, lastSyncTime: null, revisionId: 0 } as CacheState
@@ -209,11 +250,261 @@ export class SettingsComponent implements OnInit, OnDestroy {
       this.datastoreInfo = `Revision ${datastore.revisionId} / ${date.toLocaleString('de-DE')}`;
       this.topicsCount = datastore.topics.length;
       this.membersCount = datastore.members.length;
+      this.exportTagOptions = sortLocale((datastore.tags || []).map(t => t.name));
+      this.exportTagNames = this.exportTagNames.filter(name => this.exportTagOptions.includes(name));
+      this.defaultMemberOptions = datastore.members
+        .filter(m => m.active)
+        .map(m => ({ id: m.id, displayName: m.displayName }))
+        .sort((a, b) => compareLocale(a.displayName, b.displayName));
+      this.defaultCMemberIds = [...(datastore.raciDefaults?.cMemberIds ?? [])];
+      this.defaultIMemberIds = [...(datastore.raciDefaults?.iMemberIds ?? [])];
+      this.autoRules = datastore.autoRules ?? [];
+      this.sharedPathPrefixesText = (datastore.referencePolicy?.sharedPathPrefixes ?? []).join('\n');
+      this.fileNumberPattern = datastore.referencePolicy?.fileNumberPattern ?? '';
+      this.fileNumberUppercase = datastore.referencePolicy?.fileNumberUppercase ?? false;
+      this.fileNumberUnique = datastore.referencePolicy?.fileNumberUnique ?? false;
+      this.useGermanStopWords = datastore.searchStopWords?.useGermanList ?? false;
+      this.customStopWordsText = (datastore.searchStopWords?.custom ?? []).join(', ');
     } else {
       this.datastoreInfo = '';
       this.topicsCount = null;
       this.membersCount = null;
+      this.exportTagOptions = [];
+      this.exportTagNames = [];
+      this.defaultMemberOptions = [];
+      this.defaultCMemberIds = [];
+      this.defaultIMemberIds = [];
+      this.autoRules = [];
+      this.sharedPathPrefixesText = '';
+      this.fileNumberPattern = '';
+      this.fileNumberUppercase = false;
+      this.fileNumberUnique = false;
+      this.useGermanStopWords = false;
+      this.customStopWordsText = '';
+    }
+  }
+
+  /**
+   * Store the search stop words. The search index is rebuilt automatically
+   * because the datastore changes.
+   */
+  async saveSearchStopWords(): Promise<void> {
+    const current = this.backend.getDatastore()?.searchStopWords;
+    const custom = parseStopWords(this.customStopWordsText);
+    if (this.useGermanStopWords === (current?.useGermanList ?? false)
+      && custom.join(',') === (current?.custom ?? []).join(',')) {
+      return;
+    }
+    const success = await this.backend.updateSearchStopWords({ useGermanList: this.useGermanStopWords, custom });
+    if (!success) {
+      this.messageService.add({
+        severity: 'error',
+        summary: 'Fehler',
+        detail: 'Stoppwörter konnten nicht gespeichert werden.'
+      });
+    }
+  }
+
+  /**
+   * Store the allowed shared path prefixes (one per line, normalized).
+   */
+  async saveSharedPathPrefixes(): Promise<void> {
+    const current = this.backend.getDatastore()?.referencePolicy;
+    const sharedPathPrefixes = [...new Set(this.sharedPathPrefixesText
+      .split('\n')
+      .map(line => normalizeSharedPath(line))
+      .filter(line => line !== ''))];
+    if (sharedPathPrefixes.join('\n') === (current?.sharedPathPrefixes ?? []).join('\n')) {
+      return;
+    }
+    const invalid = sharedPathPrefixes.filter(prefix => !isValidSharedPath(prefix));
+    if (invalid.length > 0) {
+      this.messageService.add({
+        severity: 'warn',
+        summary: 'Ungültige Ablageorte',
+        detail: invalid.join(', ')
+      });
+      return;
+    }
+    await this.saveReferencePolicy({ ...current, sharedPathPrefixes });
+  }
+
+  /**
+   * Store the file number policy (format pattern, upper case, uniqueness).
+   */
+  async saveFileNumberPolicy(): Promise<void> {
+    const pattern = this.fileNumberPattern.trim();
+    if (pattern && !isValidFileNumberPattern(pattern)) {
+      this.messageService.add({
+        severity: 'warn',
+        summary: 'Ungültiges Format',
+        detail: 'Das Aktenzeichen-Format ist kein gültiger regulärer Ausdruck.'
+      });
+      return;
+    }
+    await this.saveReferencePolicy({
+      ...this.backend.getDatastore()?.referencePolicy,
+      fileNumberPattern: pattern || undefined,
+      fileNumberUppercase: this.fileNumberUppercase,
+      fileNumberUnique: this.fileNumberUnique
+    });
+  }
+
+  private async saveReferencePolicy(referencePolicy: ReferencePolicy): Promise<void> {
+    const success = await this.backend.updateReferencePolicy(referencePolicy);
+    if (!success) {
+      this.messageService.add({
+        severity: 'error',
+        summary: 'Fehler',
+        detail: 'Referenz-Vorgaben konnten nicht gespeichert werden.'
+      });
+    }
+  }
+
+  /**
+   * Store the workspace RACI defaults that are added to new topics.
+   */
+  async saveRaciDefaults(): Promise<void> {
+    const success = await this.backend.updateRaciDefaults({
+      cMemberIds: this.defaultCMemberIds,
+      iMemberIds: this.defaultIMemberIds
+    });
+    if (!success) {
+      this.messageService.add({
+        severity: 'error',
+        summary: 'Fehler',
+        detail: 'RACI-Vorgaben konnten nicht gespeichert werden.'
+      });
+    }
+  }
+
+  canAddAutoRule(): boolean {
+    return this.newRuleName.trim() !== '' && this.newRuleContains.trim() !== ''
+      && (this.newRuleTags.length > 0 || this.newRulePriority !== null);
+  }
+
+  async addAutoRule(): Promise<void> {
+    if (!this.canAddAutoRule()) {
+      return;
+    }
+    const rule: AutoRule = {
+      id: this.backend.generateUUID(),
+      name: this.newRuleName.trim(),
+      field: this.newRuleField,
+      contains: this.newRuleContains.trim(),
+      ...(this.newRuleTags.length > 0 ? { addTags: [...this.newRuleTags] } : {}),
+      ...(this.newRulePriority !== null ? { setPriority: this.newRulePriority } : {}),
+      enabled: true
+    };
+    if (await this.saveAutoRules([...this.autoRules, rule])) {
+      this.newRuleName = '';
+      this.newRuleContains = '';
+      this.newRuleTags = [];
+      this.newRulePriority = null;
+    }
+  }
+
+  async removeAutoRule(rule: AutoRule): Promise<void> {
+    await this.saveAutoRules(this.autoRules.filter(r => r.id !== rule.id));
+  }
+
+  async setAutoRuleEnabled(rule: AutoRule, enabled: boolean): Promise<void> {
+    await this.saveAutoRules(this.autoRules.map(r => r.id === rule.id ? { ...r, enabled } : r));
+  }
+
+  describeAutoRule(rule: AutoRule): string {
+    const actions = [
+      ...(rule.addTags?.length ? [`Tags: ${rule.addTags.join(', ')}`] : []),
+      ...(rule.setPriority !== undefined ? [`Priorität ${rule.setPriority}`] : [])
+    ];
+    return `${AUTO_RULE_FIELD_LABELS[rule.field]} enthält „${rule.contains}“ → ${actions.join(', ')}`;
+  }
+
+  private async saveAutoRules(autoRules: AutoRule[]): Promise<boolean> {
+    const success = await this.backend.updateAutoRules(autoRules);
+    if (!success) {
+      this.messageService.add({
+        severity: 'error',
+        summary: 'Fehler',
+        detail: 'Regeln konnten nicht gespeichert werden.'
+      });
     }
+    return success;
+  }
+
+  /**
+   * Apply the enabled rules to all existing topics (e.g. after a large import).
+   */
+  runAutoRules(): void {
+    const datastore = this.backend.getDatastore();
+    if (!datastore) {
+      return;
+    }
+    const updates = datastore.topics
+      .map(topic => ({ topic, updated: applyAutoRules(topic, this.autoRules) }))
+      .filter(entry => entry.updated !== entry.topic)
+      .map(entry => ({ topicId: entry.topic.id, changes: { tags: entry.updated.tags, priority: entry.updated.priority } }));
+
+    if (updates.length === 0) {
+      this.messageService.add({
+        severity: 'info',
+        summary: 'Regeln',
+        detail: 'Keine Themen betroffen.'
+      });
+      return;
+    }
+
+    this.confirmationService.confirm({
+      message: `Die Regeln ändern ${updates.length} Themen. Fortfahren?`,
+      header: 'Regeln anwenden',
+      icon: 'pi pi-exclamation-triangle',
+      acceptLabel: 'Anwenden',
+      rejectLabel: 'Abbrechen',
+      accept: async () => {
+        const success = await this.backend.updateMultipleTopics(updates);
+        this.messageService.add(success
+          ? { severity: 'success', summary: 'Regeln', detail: `${updates.length} Themen aktualisiert.` }
+          : { severity: 'error', summary: 'Fehler', detail: 'Regeln konnten nicht angewendet werden.' });
+      }
+    });
+  }
+
+  /**
+   * Add the RACI defaults to all existing topics that do not have them yet.
+   */
+  reapplyRaciDefaults(): void {
+    const datastore = this.backend.getDatastore();
+    if (!datastore?.raciDefaults) {
+      return;
+    }
+    const raciDefaults = datastore.raciDefaults;
+    const updates = datastore.topics
+      .map(topic => ({ topicId: topic.id, raci: topic.raci, updated: applyRaciDefaults(topic.raci, raciDefaults) }))
+      .filter(entry => entry.updated !== entry.raci)
+      .map(entry => ({ topicId: entry.topicId, changes: { raci: entry.updated } }));
+
+    if (updates.length === 0) {
+      this.messageService.add({
+        severity: 'info',
+        summary: 'RACI-Vorgaben',
+        detail: 'Alle Themen enthalten die Vorgaben bereits.'
+      });
+      return;
+    }
+
+    this.confirmationService.confirm({
+      message: `Die Vorgaben werden in ${updates.length} Themen ergänzt. Fortfahren?`,
+      header: 'RACI-Vorgaben anwenden',
+      icon: 'pi pi-exclamation-triangle',
+      acceptLabel: 'Anwenden',
+      rejectLabel: 'Abbrechen',
+      accept: async () => {
+        const success = await this.backend.updateMultipleTopics(updates);
+        this.messageService.add(success
+          ? { severity: 'success', summary: 'RACI-Vorgaben', detail: `${updates.length} Themen ergänzt.` }
+          : { severity: 'error', summary: 'Fehler', detail: 'RACI-Vorgaben konnten nicht angewendet werden.' });
+      }
+    });
   }
 
   private async checkCanBootstrap(): Promise<void> {
@@ -225,6 +516,37 @@ export class SettingsComponent implements OnInit, OnDestroy {
     }
   }
 
+  /**
+   * Download a self-consistent subset of the datastore (topics with the selected tags
+   * plus the tags and members they reference) as JSON for import into another folder.
+   * Without selected tags, all topics are exported.
+   */
+  exportDatastore(): void {
+    const datastore = this.backend.getDatastore();
+    if (!datastore) {
+      return;
+    }
+
+    const exported = createPartialExport(datastore, {
+      tagNames: this.exportTagNames,
+      includeMembers: this.exportMemberScope
+    });
+
+    const blob = new Blob([JSON.stringify(exported, null, 2)], { type: 'application/json' });
+    const url = URL.createObjectURL(blob);
+    const link = document.createElement('a');
+    link.href = url;
+    link.download = `datastore-export-${new Date().toISOString().split('T')[0]}.json`;
+    link.click();
+    URL.revokeObjectURL(url);
+
+    this.messageService.add({
+      severity: 'success',
+      summary: 'Export erstellt',
+      detail: `${exported.topics.length} Themen, ${exported.members.length} Mitglieder und ${exported.tags?.length ?? 0} Tags exportiert.`
+    });
+  }
+
   /**
    * Get operation type info for display (icon and severity).
    */
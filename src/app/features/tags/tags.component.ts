@@ -16,12 +16,17 @@ import { InputIcon } from 'primeng/inputicon';
 import { Tooltip } from 'primeng/tooltip';
 import { ColorPicker } from 'primeng/colorpicker';
 import { ToggleSwitch } from 'primeng/toggleswitch';
+import { MultiSelect } from 'primeng/multiselect';
+import { Message } from 'primeng/message';
 import { MessageService, ConfirmationService } from 'primeng/api';
 import { Subscription } from 'rxjs';
 import { BackendService } from '../../core/services/backend.service';
-import { Tag, TeamMember, Datastore } from '../../core/models';
+import { Tag, TeamMember, Topic, Datastore } from '../../core/models';
 import { isValidKeyword, sanitizeKeyword } from '../../shared/utils/validation.utils';
 import { TAG_WEIGHT_RECOMMENDED_MIN, TAG_WEIGHT_RECOMMENDED_MAX } from '../../core/services/load-calculation.service';
+import { compareLocale, sortLocale } from '../../shared/utils/sort.utils';
+import { pickDistinctColor } from '../../shared/utils/color.utils';
+import { computeTagAssignment, countTagUsage, findTagOrphans } from '../../shared/utils/tag.utils';
 
 @Component({
   selector: 'app-tags',
@@ -43,6 +48,8 @@ import { TAG_WEIGHT_RECOMMENDED_MIN, TAG_WEIGHT_RECOMMENDED_MAX } from '../../co
     Tooltip,
     ColorPicker,
     ToggleSwitch,
+    MultiSelect,
+    Message,
   ],
   providers: [MessageService, ConfirmationService],
   templateUrl: './tags.component.html',
@@ -53,6 +60,7 @@ export class TagsComponent implements OnInit, OnDestroy {
   @ViewChild('dt') table!: Table;
 
   tags: (Tag & { searchKeywordsString?: string; createdByName?: string })[] = [];
+  private allTags: (Tag & { searchKeywordsString?: string; createdByName?: string })[] = [];
   tag: Tag = this.createEmptyTag();
   members: TeamMember[] = [];
   
@@ -64,6 +72,16 @@ export class TagsComponent implements OnInit, OnDestroy {
 
   globalFilter: string = '';
 
+  assignDialog: boolean = false;
+  assignTagName: string = '';
+  assignTopicIds: string[] = [];
+  topicOptions: { label: string; value: string }[] = [];
+  private topics: Topic[] = [];
+
+  showUnusedOnly: boolean = false;
+  unusedTagCount: number = 0;
+  unknownTagNames: Array<{ name: string; count: number }> = [];
+
   allKeywords: string[] = [];
   keywordSuggestions: string[] = [];
 
@@ -101,24 +119,28 @@ export class TagsComponent implements OnInit, OnDestroy {
 
   private loadData(datastore: Datastore): void {
     this.members = datastore.members;
+    this.topics = datastore.topics;
+    this.topicOptions = datastore.topics
+      .map(topic => ({ label: topic.header, value: topic.id }))
+      .sort((a, b) => compareLocale(a.label, b.label));
     
-    this.usageCountCache.clear();
-    datastore.topics.forEach(topic => {
-      topic.tags?.forEach(tagName => {
-        const current = this.usageCountCache.get(tagName) || 0;
-        this.usageCountCache.set(tagName, current + 1);
-      });
-    });
+    this.usageCountCache = countTagUsage(datastore.topics);
 
-    this.tags = (datastore.tags || []).map(tag => ({
+    const orphans = findTagOrphans(datastore.tags || [], datastore.topics);
+    this.unusedTagCount = orphans.unusedTags.length;
+    // Without managed tags every topic tag is unknown (legacy mode), so don't report them
+    this.unknownTagNames = (datastore.tags || []).length > 0 ? orphans.unknownTagNames : [];
+
+    this.allTags = (datastore.tags || []).map(tag => ({
       ...tag,
       searchKeywordsString: tag.searchKeywords?.join(' ') || '',
       createdByName: this.getMemberName(tag.createdBy)
     }));
+    this.applyUnusedFilter();
 
     const keywordSet = new Set<string>();
     (datastore.tags || []).forEach(t => t.searchKeywords?.forEach(kw => keywordSet.add(kw)));
-    this.allKeywords = Array.from(keywordSet).sort();
+    this.allKeywords = sortLocale(keywordSet);
   }
 
   getMemberName(memberId: string): string {
@@ -130,6 +152,27 @@ export class TagsComponent implements OnInit, OnDestroy {
     return this.usageCountCache.get(tagName) || 0;
   }
 
+  /**
+   * Toggles between all tags and only tags not used by any topic.
+   */
+  toggleUnusedOnly(): void {
+    this.showUnusedOnly = !this.showUnusedOnly;
+    this.applyUnusedFilter();
+  }
+
+  private applyUnusedFilter(): void {
+    if (this.showUnusedOnly && this.unusedTagCount === 0) {
+      this.showUnusedOnly = false;
+    }
+    this.tags = this.showUnusedOnly
+      ? this.allTags.filter(tag => this.getUsageCount(tag.name) === 0)
+      : this.allTags;
+  }
+
+  getUnknownTagNamesDisplay(): string {
+    return this.unknownTagNames.map(t => `${t.name} (${t.count})`).join(', ');
+  }
+
   searchKeywords(event: { query: string }): void {
     const query = event.query.toLowerCase();
     // Sanitize the query for suggestions
@@ -243,7 +286,7 @@ export class TagsComponent implements OnInit, OnDestroy {
     if (this.editMode && normalizedName === this.originalTagName.toLowerCase()) {
       return false;
     }
-    return this.tags.some(t => t.name.toLowerCase() === normalizedName);
+    return this.allTags.some(t => t.name.toLowerCase() === normalizedName);
   }
 
   async saveTag(): Promise<void> {
@@ -271,6 +314,9 @@ export class TagsComponent implements OnInit, OnDestroy {
         this.tag.id = this.backend.generateUUID();
         this.tag.createdAt = new Date().toISOString();
         this.tag.modifiedAt = new Date().toISOString();
+        if (!this.tag.color) {
+          this.tag.color = pickDistinctColor(this.tag.name, this.allTags.map(t => t.color));
+        }
         success = await this.backend.addTag(this.tag);
       }
 
@@ -342,6 +388,56 @@ export class TagsComponent implements OnInit, OnDestroy {
     }
   }
 
+  /**
+   * Opens the dialog to assign a tag to many topics at once.
+   */
+  openAssignDialog(tag: Tag): void {
+    this.assignTagName = tag.name;
+    this.assignTopicIds = this.topics
+      .filter(topic => topic.tags?.includes(tag.name))
+      .map(topic => topic.id);
+    this.assignDialog = true;
+  }
+
+  /**
+   * Adds the tag to all selected topics and removes it from all others
+   * in a single save.
+   */
+  async saveAssignment(): Promise<void> {
+    const { updates, added, removed } = computeTagAssignment(this.topics, this.assignTagName, this.assignTopicIds);
+    if (updates.length === 0) {
+      this.assignDialog = false;
+      return;
+    }
+
+    this.saving = true;
+    try {
+      const success = await this.backend.updateMultipleTopics(updates);
+      if (success) {
+        this.messageService.add({
+          severity: 'success',
+          summary: 'Erfolgreich',
+          detail: `Tag "${this.assignTagName}": ${added} Themen zugeordnet, aus ${removed} Themen entfernt`
+        });
+        this.assignDialog = false;
+      } else {
+        this.messageService.add({
+          severity: 'error',
+          summary: 'Fehler',
+          detail: 'Speichern fehlgeschlagen. Möglicherweise ist die Datei gesperrt.'
+        });
+      }
+    } catch (error) {
+      this.messageService.add({
+        severity: 'error',
+        summary: 'Fehler',
+        detail: 'Unerwarteter Fehler beim Speichern: ' + (error as Error).message
+      });
+    } finally {
+      this.saving = false;
+    }
+  }
+
   onGlobalFilter(event: Event): void {
     const value = (event.target as HTMLInputElement).value;
     this.table?.filterGlobal(value, 'contains');
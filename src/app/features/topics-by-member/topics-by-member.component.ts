@@ -14,6 +14,8 @@ import { BackendService } from '../../core/services/backend.service';
 import { Topic, TeamMember, Datastore, Tag as TagModel, TShirtSize } from '../../core/models';
 import { getSizeSeverity } from '../../shared/utils/topic-display.utils';
 import { PageWrapperComponent } from '../../shared/components';
+import { compareLocale, sortLocale } from '../../shared/utils/sort.utils';
+import { getValidityStatus, ValidityStatus } from '../../shared/utils/validity.utils';
 
 interface MemberOption {
   id: string;
@@ -96,7 +98,7 @@ export class TopicsByMemberComponent implements OnInit, OnDestroy {
     this.members()
       .filter(m => m.active)
       .map(m => ({ id: m.id, displayName: m.displayName }))
-      .sort((a, b) => a.displayName.localeCompare(b.displayName))
+      .sort((a, b) => compareLocale(a.displayName, b.displayName))
   );
 
   // Computed: selected member name
@@ -112,7 +114,7 @@ export class TopicsByMemberComponent implements OnInit, OnDestroy {
     this.topics().forEach(topic => {
       topic.tags?.forEach(tag => tagSet.add(tag));
     });
-    return Array.from(tagSet).sort();
+    return sortLocale(tagSet);
   });
 
   // Computed: topics for selected member
@@ -342,17 +344,8 @@ export class TopicsByMemberComponent implements OnInit, OnDestroy {
     return getSizeSeverity(size);
   }
 
-  getValidityStatus(topic: Topic): string {
-    if (topic.validity.alwaysValid) return 'always';
-
-    const now = new Date();
-    const validFrom = topic.validity.validFrom ? new Date(topic.validity.validFrom) : null;
-    const validTo = topic.validity.validTo ? new Date(topic.validity.validTo) : null;
-
-    if (validFrom && now < validFrom) return 'future';
-    if (validTo && now > validTo) return 'expired';
-    
-    return 'valid';
+  getValidityStatus(topic: Topic): ValidityStatus {
+    return getValidityStatus(topic.validity);
   }
 
   getValidityLabel(topic: Topic): string {
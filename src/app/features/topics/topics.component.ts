@@ -1,4 +1,4 @@
-import { Component, OnInit, OnDestroy, ViewChild, ChangeDetectionStrategy } from '@angular/core';
+import { Component, OnInit, OnDestroy, ViewChild, ChangeDetectionStrategy, HostListener } from '@angular/core';
 import { CommonModule } from '@angular/common';
 import { FormsModule } from '@angular/forms';
 import { Table, TableModule } from 'primeng/table';
@@ -20,15 +20,26 @@ import { IconField } from 'primeng/iconfield';
 import { InputIcon } from 'primeng/inputicon';
 import { Rating } from 'primeng/rating';
 import { Tooltip } from 'primeng/tooltip';
-import { MessageService, ConfirmationService } from 'primeng/api';
+import { MessageService, ConfirmationService, ConfirmEventType } from 'primeng/api';
 import { Subscription } from 'rxjs';
 import { BackendService } from '../../core/services/backend.service';
 import { IrregularTaskService, IrregularTaskResult, IrregularTaskValidation } from '../../core/services/irregular-task.service';
-import { Topic, TeamMember, Datastore, Tag as TagModel, TShirtSize, TopicConnection, TopicConnectionType, TaskCategory, TopicType, DEFAULT_IRREGULAR_ESTIMATION, VARIANCE_CLASS_OPTIONS, WAVE_CLASS_OPTIONS } from '../../core/models';
+import { TopicDraftService, TopicDraft } from '../../core/services/topic-draft.service';
+import { LoadCalculationService } from '../../core/services/load-calculation.service';
+import { Topic, TeamMember, Datastore, RotationPeriod, Tag as TagModel, TShirtSize, TopicConnection, TopicConnectionType, TaskCategory, TopicType, DEFAULT_IRREGULAR_ESTIMATION, VARIANCE_CLASS_OPTIONS, WAVE_CLASS_OPTIONS } from '../../core/models';
 import { getPriorityStars, getSizeSeverity } from '../../shared/utils/topic-display.utils';
 import { isValidKeyword, sanitizeKeyword } from '../../shared/utils/validation.utils';
 import { formatHoursMinutes } from '../../shared/utils/time-format.utils';
 import { PageWrapperComponent } from '../../shared/components';
+import { compareLocale, sortLocale } from '../../shared/utils/sort.utils';
+import { formatValidityDate, getValidityStatus, isValidityActive, parseValidityDate, toValidityDateString } from '../../shared/utils/validity.utils';
+import { suggestKeywords } from '../../shared/utils/keyword-suggestion.utils';
+import { GERMAN_STOP_WORDS, buildStopWordSet } from '../../shared/utils/stop-words.utils';
+import { formatFileNumber, getFileNumberError } from '../../shared/utils/file-number.utils';
+import { getSharedPathError, normalizeSharedPath } from '../../shared/utils/file-path.utils';
+import { applyRaciDefaults } from '../../shared/utils/raci-defaults.utils';
+import { HeaderRenamePreview, previewHeaderRename } from '../../shared/utils/header-rename.utils';
+import { getRotationMemberId, isDelegationActive, isValidDelegation, isValidRotation } from '../../shared/utils/responsible.utils';
 
 interface MemberOption {
   id: string;
@@ -95,6 +106,36 @@ export class TopicsComponent implements OnInit, OnDestroy {
   validFromDate: Date | null = null;
   validToDate: Date | null = null;
 
+  // Delegation dialog
+  delegationDialog: boolean = false;
+  delegationTopic: Topic | null = null;
+  delegationMemberId: string = '';
+  delegationFrom: Date | null = null;
+  delegationTo: Date | null = null;
+  delegationReason: string = '';
+
+  // Rotation dialog
+  rotationDialog: boolean = false;
+  rotationTopic: Topic | null = null;
+  rotationMemberIds: string[] = [];
+  rotationPeriod: RotationPeriod = 'weekly';
+  rotationStart: Date | null = null;
+  rotationPeriodOptions = [
+    { label: 'Wöchentlich', value: 'weekly' },
+    { label: 'Monatlich', value: 'monthly' }
+  ];
+
+  // Header find-and-replace dialog
+  renameDialog: boolean = false;
+  renamePattern: string = '';
+  renameReplacement: string = '';
+  renameUseRegex: boolean = false;
+  renameCaseSensitive: boolean = false;
+  renamePreview: HeaderRenamePreview = { changes: [], rejected: [], error: '' };
+
+  /** Keyword suggestions derived from the topic content, computed on request */
+  suggestedKeywords: string[] = [];
+
   allTags: string[] = [];
   allKeywords: string[] = [];
   tagSuggestions: string[] = [];
@@ -158,13 +199,24 @@ export class TopicsComponent implements OnInit, OnDestroy {
   managedTags: TagModel[] = [];
   managedTagsExist: boolean = false;
 
+  /**
+   * Topic ID whose dialog edits are tracked as a draft ('' for a new topic),
+   * or null when there is nothing to keep (dialog closed or topic saved).
+   */
+  private draftTopicId: string | null = null;
+
+  /** Serialized dialog state when it was opened, to detect unsaved changes */
+  private draftSnapshot: string = '';
+
   private subscriptions: Subscription[] = [];
 
   constructor(
     private backend: BackendService,
     private messageService: MessageService,
     private confirmationService: ConfirmationService,
-    private irregularTaskService: IrregularTaskService
+    private irregularTaskService: IrregularTaskService,
+    private topicDraftService: TopicDraftService,
+    private loadCalculationService: LoadCalculationService
   ) {}
 
   ngOnInit(): void {
@@ -191,8 +243,11 @@ export class TopicsComponent implements OnInit, OnDestroy {
     this.members = datastore.members;
     this.activeMembers = datastore.members
       .filter(m => m.active)
-      .map(m => ({ id: m.id, displayName: m.displayName }));
-    this.memberOptions = datastore.members.map(m => ({ id: m.id, displayName: m.displayName }));
+      .map(m => ({ id: m.id, displayName: m.displayName }))
+      .sort((a, b) => compareLocale(a.displayName, b.displayName));
+    this.memberOptions = datastore.members
+      .map(m => ({ id: m.id, displayName: m.displayName }))
+      .sort((a, b) => compareLocale(a.displayName, b.displayName));
 
     this.managedTags = datastore.tags || [];
     this.managedTagsExist = this.managedTags.length > 0;
@@ -207,11 +262,11 @@ export class TopicsComponent implements OnInit, OnDestroy {
     // Build all topic options for connection selection
     this.topicOptions = datastore.topics.map(t => ({ id: t.id, header: t.header }));
 
-    this.allTags = this.managedTags.map(t => t.name).sort();
+    this.allTags = sortLocale(this.managedTags.map(t => t.name));
 
     const keywordSet = new Set<string>();
     datastore.topics.forEach(t => t.searchKeywords?.forEach(kw => keywordSet.add(kw)));
-    this.allKeywords = Array.from(keywordSet).sort();
+    this.allKeywords = sortLocale(keywordSet);
   }
 
   searchTags(event: { query: string }): void {
@@ -255,6 +310,33 @@ export class TopicsComponent implements OnInit, OnDestroy {
     }
   }
 
+  /**
+   * Suggests keywords from header, description and notes of the edited topic.
+   */
+  suggestKeywords(): void {
+    const datastore = this.backend.getDatastore();
+    const stopWords = new Set([...GERMAN_STOP_WORDS, ...buildStopWordSet(datastore?.searchStopWords)]);
+    this.suggestedKeywords = suggestKeywords(this.topic, datastore?.topics ?? [], 5, stopWords)
+      .map(keyword => sanitizeKeyword(keyword))
+      .filter(keyword => isValidKeyword(keyword));
+    if (this.suggestedKeywords.length === 0) {
+      this.messageService.add({
+        severity: 'info',
+        summary: 'Suchbegriffe',
+        detail: 'Keine Vorschläge gefunden. Ergänzen Sie Beschreibung oder Notizen.',
+        life: 3000
+      });
+    }
+  }
+
+  addSuggestedKeyword(keyword: string): void {
+    const keywords = this.topic.searchKeywords ?? [];
+    if (!keywords.includes(keyword)) {
+      this.topic.searchKeywords = [...keywords, keyword];
+    }
+    this.suggestedKeywords = this.suggestedKeywords.filter(k => k !== keyword);
+  }
+
   /**
    * Validates and sanitizes a keyword when it's added.
    * Called from the onAdd event of the autocomplete.
@@ -322,34 +404,24 @@ export class TopicsComponent implements OnInit, OnDestroy {
 
   openNewDialog(): void {
     this.topic = this.createEmptyTopic();
+    // Workspace defaults are filled in visibly so they can be adjusted before saving
+    this.topic.raci = applyRaciDefaults(this.topic.raci, this.backend.getDatastore()?.raciDefaults);
     this.validFromDate = null;
     this.validToDate = null;
     this.submitted = false;
     this.editMode = false;
     this.isContainerTopic = false;
     this.updateAvailableTopicsForConnection();
+    this.suggestedKeywords = [];
     this.topicDialog = true;
+    this.startDraftTracking('');
   }
 
   editTopic(topic: Topic): void {
-    this.topic = { 
-      ...topic,
-      tags: [...(topic.tags || [])],
-      searchKeywords: [...(topic.searchKeywords || [])],
-      validity: { ...topic.validity },
-      raci: { 
-        ...topic.raci,
-        cMemberIds: [...topic.raci.cMemberIds],
-        iMemberIds: [...topic.raci.iMemberIds]
-      },
-      connections: topic.connections ? topic.connections.map(c => ({ ...c })) : [],
-      taskCategory: topic.taskCategory || 'REGULAR',
-      irregularEstimation: topic.irregularEstimation ? { ...topic.irregularEstimation } : undefined,
-      topicType: topic.topicType
-    };
+    this.topic = this.copyTopic(topic);
     
-    this.validFromDate = topic.validity.validFrom ? new Date(topic.validity.validFrom) : null;
-    this.validToDate = topic.validity.validTo ? new Date(topic.validity.validTo) : null;
+    this.validFromDate = parseValidityDate(topic.validity.validFrom);
+    this.validToDate = parseValidityDate(topic.validity.validTo);
     
     // Sync container toggle
     this.isContainerTopic = topic.topicType === 'container';
@@ -360,14 +432,169 @@ export class TopicsComponent implements OnInit, OnDestroy {
     this.submitted = false;
     this.editMode = true;
     this.updateAvailableTopicsForConnection();
+    this.suggestedKeywords = [];
+    this.topicDialog = true;
+    this.startDraftTracking(topic.id, topic.updatedAt);
+  }
+
+  /**
+   * Opens the dialog for a new topic prefilled with all fields of an existing topic.
+   * The copy gets a new ID on save; authorship is set anew, and delegations and the
+   * file number are not copied.
+   */
+  cloneTopic(topic: Topic): void {
+    this.topic = {
+      ...this.copyTopic(topic),
+      id: '',
+      key: undefined,
+      slug: undefined,
+      slugAliases: undefined,
+      header: `${topic.header} (Kopie)`,
+      createdBy: undefined,
+      updatedBy: undefined,
+      delegation: undefined,
+      sizeHistory: undefined,
+      hasFileNumber: false,
+      fileNumber: '',
+      rotation: topic.rotation ? { ...topic.rotation, memberIds: [...topic.rotation.memberIds] } : undefined,
+      updatedAt: new Date().toISOString()
+    };
+
+    this.validFromDate = parseValidityDate(topic.validity.validFrom);
+    this.validToDate = parseValidityDate(topic.validity.validTo);
+    this.isContainerTopic = topic.topicType === 'container';
+    this.updateP80Calculation();
+
+    this.submitted = false;
+    this.editMode = false;
+    this.updateAvailableTopicsForConnection();
+    this.suggestedKeywords = [];
     this.topicDialog = true;
+    // A copy is not tracked as a draft: it would take over the new-topic draft slot
+    this.draftTopicId = null;
+  }
+
+  /**
+   * Deep copy of a topic for editing in the dialog.
+   */
+  private copyTopic(topic: Topic): Topic {
+    return {
+      ...topic,
+      tags: [...(topic.tags || [])],
+      searchKeywords: [...(topic.searchKeywords || [])],
+      validity: { ...topic.validity },
+      raci: {
+        ...topic.raci,
+        cMemberIds: [...topic.raci.cMemberIds],
+        iMemberIds: [...topic.raci.iMemberIds],
+        escalationMemberIds: topic.raci.escalationMemberIds ? [...topic.raci.escalationMemberIds] : undefined
+      },
+      connections: topic.connections ? topic.connections.map(c => ({ ...c })) : [],
+      taskCategory: topic.taskCategory || 'REGULAR',
+      irregularEstimation: topic.irregularEstimation ? { ...topic.irregularEstimation } : undefined,
+      topicType: topic.topicType
+    };
   }
 
   hideDialog(): void {
+    this.storeDraftIfChanged();
     this.topicDialog = false;
     this.submitted = false;
   }
 
+  /**
+   * Called when the edit dialog is closed by any means (cancel, close icon, escape).
+   */
+  onDialogHide(): void {
+    this.storeDraftIfChanged();
+    this.draftTopicId = null;
+  }
+
+  /**
+   * Keeps unsaved dialog changes when the browser tab is closed or reloaded.
+   */
+  @HostListener('window:beforeunload')
+  onBeforeUnload(): void {
+    if (this.topicDialog) {
+      this.storeDraftIfChanged();
+    }
+  }
+
+  /**
+   * Starts tracking the dialog state for drafts and offers to resume
+   * a stored draft for this topic.
+   *
+   * @param topicId - ID of the edited topic, '' for a new topic
+   * @param topicUpdatedAt - Last modification of the stored topic, to warn about outdated drafts
+   */
+  private startDraftTracking(topicId: string, topicUpdatedAt?: string): void {
+    this.draftTopicId = topicId;
+    this.draftSnapshot = JSON.stringify(this.getDialogState());
+
+    const draft = this.topicDraftService.getDraft(topicId);
+    if (!draft) {
+      return;
+    }
+
+    const outdated = topicUpdatedAt !== undefined && topicUpdatedAt > draft.savedAt;
+    this.confirmationService.confirm({
+      message: `Es gibt einen ungespeicherten Entwurf vom ${this.formatDate(draft.savedAt)}. Möchten Sie ihn fortsetzen?` +
+        (outdated ? ' Achtung: Das Thema wurde seitdem geändert.' : ''),
+      header: 'Entwurf gefunden',
+      icon: 'pi pi-file-edit',
+      acceptLabel: 'Entwurf fortsetzen',
+      rejectLabel: 'Verwerfen',
+      accept: () => this.applyDraft(draft),
+      reject: (type: ConfirmEventType) => {
+        // Only an explicit "Verwerfen" discards; closing the prompt keeps the draft
+        if (type === ConfirmEventType.REJECT) {
+          this.topicDraftService.discardDraft(topicId);
+        }
+      }
+    });
+  }
+
+  /**
+   * Loads a stored draft into the edit dialog.
+   */
+  private applyDraft(draft: TopicDraft): void {
+    const id = this.topic.id;
+    this.topic = { ...draft.topic, id };
+    this.validFromDate = parseValidityDate(draft.topic.validity.validFrom);
+    this.validToDate = parseValidityDate(draft.topic.validity.validTo);
+    this.isContainerTopic = draft.topic.topicType === 'container';
+    this.updateP80Calculation();
+  }
+
+  /**
+   * Stores the current dialog state as a draft if it differs from the state
+   * the dialog was opened with.
+   */
+  private storeDraftIfChanged(): void {
+    if (this.draftTopicId === null) {
+      return;
+    }
+    const state = this.getDialogState();
+    if (JSON.stringify(state) !== this.draftSnapshot) {
+      this.topicDraftService.saveDraft(this.draftTopicId, state);
+      this.draftSnapshot = JSON.stringify(state);
+    }
+  }
+
+  /**
+   * Current dialog state as a topic, including the validity dates held in separate fields.
+   */
+  private getDialogState(): Topic {
+    return {
+      ...this.topic,
+      validity: {
+        ...this.topic.validity,
+        validFrom: this.topic.validity.alwaysValid ? undefined : toValidityDateString(this.validFromDate),
+        validTo: this.topic.validity.alwaysValid ? undefined : toValidityDateString(this.validToDate)
+      }
+    };
+  }
+
   async saveTopic(): Promise<void> {
     this.submitted = true;
 
@@ -381,17 +608,29 @@ export class TopicsComponent implements OnInit, OnDestroy {
       return;
     }
 
+    if (this.getSharedFilePathError() || this.getFileNumberError()) {
+      return;
+    }
+    if (this.topic.hasFileNumber && this.topic.fileNumber) {
+      this.topic.fileNumber = formatFileNumber(this.topic.fileNumber, this.backend.getDatastore()?.referencePolicy);
+    }
+    if (this.topic.hasSharedFilePath && this.topic.sharedFilePath) {
+      this.topic.sharedFilePath = normalizeSharedPath(this.topic.sharedFilePath);
+    }
+
     this.saving = true;
 
     try {
       if (!this.topic.validity.alwaysValid) {
-        this.topic.validity.validFrom = this.toDateString(this.validFromDate);
-        this.topic.validity.validTo = this.toDateString(this.validToDate);
+        this.topic.validity.validFrom = toValidityDateString(this.validFromDate);
+        this.topic.validity.validTo = toValidityDateString(this.validToDate);
       } else {
         this.topic.validity.validFrom = undefined;
         this.topic.validity.validTo = undefined;
       }
 
+      const overloadWarning = this.getOverloadWarning(this.topic);
+
       let success: boolean;
       if (this.editMode) {
         success = await this.backend.updateTopic(this.topic.id, this.topic);
@@ -402,11 +641,23 @@ export class TopicsComponent implements OnInit, OnDestroy {
       }
 
       if (success) {
+        if (this.draftTopicId !== null) {
+          this.topicDraftService.discardDraft(this.draftTopicId);
+          this.draftTopicId = null;
+        }
         this.messageService.add({
           severity: 'success',
           summary: 'Erfolgreich',
           detail: this.editMode ? 'Thema aktualisiert' : 'Thema erstellt'
         });
+        if (overloadWarning) {
+          this.messageService.add({
+            severity: 'warn',
+            summary: 'Auslastung',
+            detail: overloadWarning,
+            life: 8000
+          });
+        }
         this.topicDialog = false;
         this.topic = this.createEmptyTopic();
       } else {
@@ -464,6 +715,270 @@ export class TopicsComponent implements OnInit, OnDestroy {
     }
   }
 
+  /**
+   * Checks whether saving the topic overloads any member (load above capacity
+   * according to the load model) and returns a warning text, or null.
+   */
+  private getOverloadWarning(topic: Topic): string | null {
+    const datastore = this.backend.getDatastore();
+    if (!datastore) {
+      return null;
+    }
+    const topicsAfter = this.editMode
+      ? datastore.topics.map(t => t.id === topic.id ? topic : t)
+      : [...datastore.topics, topic];
+    const overloaded = this.loadCalculationService.findOverloadedByChange(
+      datastore.members,
+      datastore.topics,
+      topicsAfter,
+      datastore.tags || []
+    );
+    if (overloaded.length === 0) {
+      return null;
+    }
+    const names = overloaded
+      .map(ml => `${ml.memberName} (${Math.round(ml.capacityRatio * 100)} %)`)
+      .join(', ');
+    return `Über der Kapazitätsgrenze: ${names}`;
+  }
+
+  /**
+   * Opens the dialog to temporarily delegate a topic to another member.
+   */
+  openDelegationDialog(topic: Topic): void {
+    this.delegationTopic = topic;
+    this.delegationMemberId = topic.delegation?.memberId ?? '';
+    this.delegationFrom = parseValidityDate(topic.delegation?.from) ?? new Date();
+    this.delegationTo = parseValidityDate(topic.delegation?.to);
+    this.delegationReason = topic.delegation?.reason ?? '';
+    this.delegationDialog = true;
+  }
+
+  canSaveDelegation(): boolean {
+    return isValidDelegation({
+      memberId: this.delegationMemberId,
+      from: toValidityDateString(this.delegationFrom) ?? '',
+      to: toValidityDateString(this.delegationTo) ?? ''
+    });
+  }
+
+  async saveDelegation(): Promise<void> {
+    if (!this.delegationTopic || !this.canSaveDelegation()) {
+      return;
+    }
+    await this.updateDelegation(this.delegationTopic, {
+      memberId: this.delegationMemberId,
+      from: toValidityDateString(this.delegationFrom)!,
+      to: toValidityDateString(this.delegationTo)!,
+      reason: this.delegationReason.trim() || undefined
+    }, 'Delegation gespeichert');
+  }
+
+  async removeDelegation(): Promise<void> {
+    if (!this.delegationTopic) {
+      return;
+    }
+    await this.updateDelegation(this.delegationTopic, undefined, 'Delegation aufgehoben');
+  }
+
+  private async updateDelegation(topic: Topic, delegation: Topic['delegation'], successDetail: string): Promise<void> {
+    const saved = await this.saveTopicChanges(topic, { delegation }, successDetail);
+    if (saved) {
+      this.delegationDialog = false;
+    }
+  }
+
+  /**
+   * Saves partial changes to a topic outside the edit dialog and reports the result.
+   * @returns true if the changes were saved
+   */
+  private async saveTopicChanges(topic: Topic, changes: Partial<Topic>, successDetail: string): Promise<boolean> {
+    this.saving = true;
+    try {
+      const success = await this.backend.updateTopic(topic.id, changes);
+      if (success) {
+        this.messageService.add({
+          severity: 'success',
+          summary: 'Erfolgreich',
+          detail: successDetail
+        });
+        return true;
+      }
+      this.messageService.add({
+        severity: 'error',
+        summary: 'Fehler',
+        detail: 'Speichern fehlgeschlagen. Möglicherweise ist die Datei gesperrt.'
+      });
+    } catch (error) {
+      this.messageService.add({
+        severity: 'error',
+        summary: 'Fehler',
+        detail: 'Unerwarteter Fehler beim Speichern: ' + (error as Error).message
+      });
+    } finally {
+      this.saving = false;
+    }
+    return false;
+  }
+
+  /**
+   * Opens the dialog to configure a rotation of the responsibility for a topic.
+   */
+  openRotationDialog(topic: Topic): void {
+    this.rotationTopic = topic;
+    this.rotationMemberIds = [...(topic.rotation?.memberIds ?? [])];
+    this.rotationPeriod = topic.rotation?.period ?? 'weekly';
+    this.rotationStart = parseValidityDate(topic.rotation?.startDate) ?? new Date();
+    this.rotationDialog = true;
+  }
+
+  canSaveRotation(): boolean {
+    return isValidRotation({
+      memberIds: this.rotationMemberIds,
+      period: this.rotationPeriod,
+      startDate: toValidityDateString(this.rotationStart) ?? ''
+    });
+  }
+
+  async saveRotation(): Promise<void> {
+    if (!this.rotationTopic || !this.canSaveRotation()) {
+      return;
+    }
+    await this.updateRotation(this.rotationTopic, {
+      memberIds: [...this.rotationMemberIds],
+      period: this.rotationPeriod,
+      startDate: toValidityDateString(this.rotationStart)!
+    }, 'Rotation gespeichert');
+  }
+
+  async removeRotation(): Promise<void> {
+    if (!this.rotationTopic) {
+      return;
+    }
+    await this.updateRotation(this.rotationTopic, undefined, 'Rotation entfernt');
+  }
+
+  private async updateRotation(topic: Topic, rotation: Topic['rotation'], successDetail: string): Promise<void> {
+    const saved = await this.saveTopicChanges(topic, { rotation }, successDetail);
+    if (saved) {
+      this.rotationDialog = false;
+    }
+  }
+
+  /**
+   * Returns the validation error of the file number in the editor, or an empty string.
+   */
+  getFileNumberError(): string {
+    if (!this.topic.hasFileNumber || !this.topic.fileNumber) {
+      return '';
+    }
+    const datastore = this.backend.getDatastore();
+    return getFileNumberError(this.topic.fileNumber, datastore?.referencePolicy, datastore?.topics ?? [], this.topic.id);
+  }
+
+  /**
+   * Returns the validation error of the shared file path in the editor, or an empty string.
+   */
+  getSharedFilePathError(): string {
+    if (!this.topic.hasSharedFilePath || !this.topic.sharedFilePath) {
+      return '';
+    }
+    return getSharedPathError(this.topic.sharedFilePath, this.backend.getDatastore()?.referencePolicy?.sharedPathPrefixes);
+  }
+
+  /**
+   * Opens the dialog to find and replace text in all topic headers.
+   */
+  openRenameDialog(): void {
+    this.renamePattern = '';
+    this.renameReplacement = '';
+    this.renameUseRegex = false;
+    this.renameCaseSensitive = false;
+    this.updateRenamePreview();
+    this.renameDialog = true;
+  }
+
+  /**
+   * Recomputes the dry-run preview of the header replacement.
+   */
+  updateRenamePreview(): void {
+    this.renamePreview = previewHeaderRename(this.topics, this.renamePattern, this.renameReplacement, {
+      useRegex: this.renameUseRegex,
+      caseSensitive: this.renameCaseSensitive
+    });
+  }
+
+  /**
+   * Applies the previewed header replacement to all affected topics in a single update.
+   */
+  applyRename(): void {
+    const changes = this.renamePreview.changes;
+    if (changes.length === 0) {
+      return;
+    }
+    this.confirmationService.confirm({
+      message: `${changes.length} Themenbezeichnungen werden geändert. Fortfahren?`,
+      header: 'Umbenennen bestätigen',
+      icon: 'pi pi-exclamation-triangle',
+      acceptLabel: 'Umbenennen',
+      rejectLabel: 'Abbrechen',
+      accept: async () => {
+        this.saving = true;
+        try {
+          const success = await this.backend.updateMultipleTopics(
+            changes.map(change => ({ topicId: change.topicId, changes: { header: change.after } }))
+          );
+          if (success) {
+            this.messageService.add({
+              severity: 'success',
+              summary: 'Erfolgreich',
+              detail: `${changes.length} Themen umbenannt`
+            });
+            this.renameDialog = false;
+          } else {
+            this.messageService.add({
+              severity: 'error',
+              summary: 'Fehler',
+              detail: 'Umbenennen fehlgeschlagen. Es wurden keine Themen geändert.'
+            });
+          }
+        } finally {
+          this.saving = false;
+        }
+      }
+    });
+  }
+
+  /**
+   * Returns the name of the member currently in charge according to the rotation, or null.
+   */
+  getRotationMemberName(topic: Topic): string | null {
+    const memberId = getRotationMemberId(topic.rotation);
+    return memberId ? this.getMemberName(memberId) : null;
+  }
+
+  /**
+   * Formats the size history of a topic, newest change first.
+   */
+  getSizeHistoryEntries(topic: Topic): string[] {
+    return [...(topic.sizeHistory ?? [])].reverse().map(change => {
+      const editor = change.changedBy ? `, ${this.getMemberName(change.changedBy)}` : '';
+      return `${change.from ?? '–'} → ${change.to ?? '–'} (${this.formatDate(change.changedAt)}${editor})`;
+    });
+  }
+
+  isDelegated(topic: Topic): boolean {
+    return isDelegationActive(topic.delegation);
+  }
+
+  getDelegationTooltip(topic: Topic): string {
+    if (!topic.delegation) {
+      return '';
+    }
+    const reason = topic.delegation.reason ? ` (${topic.delegation.reason})` : '';
+    return `Delegiert an ${this.getMemberName(topic.delegation.memberId)} bis ${formatValidityDate(topic.delegation.to)}${reason}`;
+  }
+
   onGlobalFilter(event: Event): void {
     const value = (event.target as HTMLInputElement).value;
     this.table?.filterGlobal(value, 'contains');
@@ -488,15 +1003,10 @@ export class TopicsComponent implements OnInit, OnDestroy {
           case 'always':
             return topic.validity.alwaysValid;
           case 'valid':
-            return this.isTopicValid(topic);
+            return isValidityActive(topic.validity, now);
           case 'future':
-            if (topic.validity.alwaysValid) return false;
-            const validFrom = topic.validity.validFrom ? new Date(topic.validity.validFrom) : null;
-            return validFrom && now < validFrom;
           case 'expired':
-            if (topic.validity.alwaysValid) return false;
-            const validTo = topic.validity.validTo ? new Date(topic.validity.validTo) : null;
-            return validTo && now > validTo;
+            return getValidityStatus(topic.validity, now) === this.filterValidity;
           default:
             return true;
         }
@@ -508,14 +1018,7 @@ export class TopicsComponent implements OnInit, OnDestroy {
   }
 
   isTopicValid(topic: Topic): boolean {
-    if (topic.validity.alwaysValid) return true;
-    const now = new Date();
-    const validFrom = topic.validity.validFrom ? new Date(topic.validity.validFrom) : null;
-    const validTo = topic.validity.validTo ? new Date(topic.validity.validTo) : null;
-    
-    if (validFrom && now < validFrom) return false;
-    if (validTo && now > validTo) return false;
-    return true;
+    return isValidityActive(topic.validity);
   }
 
   onAlwaysValidChange(): void {
@@ -543,47 +1046,31 @@ export class TopicsComponent implements OnInit, OnDestroy {
   }
 
   getValidityBadge(topic: Topic): string {
-    if (topic.validity.alwaysValid) {
-      return 'Immer gültig';
-    }
-
-    const now = new Date();
-    const validFrom = topic.validity.validFrom ? new Date(topic.validity.validFrom) : null;
-    const validTo = topic.validity.validTo ? new Date(topic.validity.validTo) : null;
-
-    if (validFrom && now < validFrom) {
-      return `Ab ${this.formatDateShort(topic.validity.validFrom!)}`;
-    }
-
-    if (validTo && now > validTo) {
-      return 'Abgelaufen';
+    switch (getValidityStatus(topic.validity)) {
+      case 'always':
+        return 'Immer gültig';
+      case 'future':
+        return `Ab ${formatValidityDate(topic.validity.validFrom)}`;
+      case 'expired':
+        return 'Abgelaufen';
+      default:
+        return topic.validity.validFrom && topic.validity.validTo
+          ? `Bis ${formatValidityDate(topic.validity.validTo)}`
+          : 'Gültig';
     }
-
-    if (validFrom && validTo) {
-      return `Bis ${this.formatDateShort(topic.validity.validTo!)}`;
-    }
-
-    return 'Gültig';
   }
 
   getValiditySeverity(topic: Topic): 'success' | 'info' | 'warn' | 'danger' | 'secondary' | 'contrast' {
-    if (topic.validity.alwaysValid) {
-      return 'success';
-    }
-
-    const now = new Date();
-    const validFrom = topic.validity.validFrom ? new Date(topic.validity.validFrom) : null;
-    const validTo = topic.validity.validTo ? new Date(topic.validity.validTo) : null;
-
-    if (validFrom && now < validFrom) {
-      return 'warn';
-    }
-
-    if (validTo && now > validTo) {
-      return 'danger';
+    switch (getValidityStatus(topic.validity)) {
+      case 'always':
+        return 'success';
+      case 'future':
+        return 'warn';
+      case 'expired':
+        return 'danger';
+      default:
+        return 'info';
     }
-
-    return 'info';
   }
 
   formatDate(isoString: string): string {
@@ -598,16 +1085,6 @@ export class TopicsComponent implements OnInit, OnDestroy {
     });
   }
 
-  formatDateShort(isoString: string): string {
-    if (!isoString) return '';
-    const date = new Date(isoString);
-    return date.toLocaleDateString('de-DE');
-  }
-
-  private toDateString(date: Date | null): string | undefined {
-    return date ? date.toISOString().split('T')[0] : undefined;
-  }
-
   getPriorityStars(priority: number | undefined): string {
     return getPriorityStars(priority);
   }
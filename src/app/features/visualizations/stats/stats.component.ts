@@ -0,0 +1,141 @@
+import { Component, ChangeDetectionStrategy, computed, inject } from '@angular/core';
+import { toSignal } from '@angular/core/rxjs-interop';
+import { Card } from 'primeng/card';
+import { BackendService } from '../../../core/services/backend.service';
+import { computeDatastoreStats } from '../../../shared/utils/datastore-stats.utils';
+
+@Component({
+  selector: 'app-stats',
+  imports: [Card],
+  template: `
+    <div class="page-container">
+      <p-card>
+        <ng-template #header>
+          <div class="card-header">
+            <h2>
+              <i class="pi pi-chart-pie"></i>
+              Statistik
+            </h2>
+            <p class="subtitle">Kennzahlen über alle Themen, Mitglieder und Tags</p>
+          </div>
+        </ng-template>
+
+        @if (stats(); as s) {
+          <div class="stat-tiles">
+            <div class="stat-tile"><span class="value">{{ s.topicCount }}</span><span class="label">Themen</span></div>
+            <div class="stat-tile"><span class="value">{{ s.memberCount }}</span><span class="label">Mitglieder</span></div>
+            <div class="stat-tile"><span class="value">{{ s.tagCount }}</span><span class="label">Tags</span></div>
+            <div class="stat-tile"><span class="value">{{ s.modifiedLast7Days }}</span><span class="label">Geändert (7 Tage)</span></div>
+            <div class="stat-tile"><span class="value">{{ s.modifiedLast30Days }}</span><span class="label">Geändert (30 Tage)</span></div>
+            <div class="stat-tile" [class.warning]="s.topicsWithoutR2 > 0">
+              <span class="value">{{ s.topicsWithoutR2 }}</span><span class="label">Ohne Vertretung (R2)</span>
+            </div>
+            <div class="stat-tile">
+              <span class="value">{{ s.averageStalenessDays ?? '–' }}</span><span class="label">Tage seit letzter Änderung (Ø)</span>
+            </div>
+          </div>
+
+          <div class="stat-lists">
+            <div>
+              <h3>Themen pro Tag</h3>
+              @for (entry of s.topicsPerTag; track entry.label) {
+                <div class="stat-row"><span>{{ entry.label }}</span><span>{{ entry.count }}</span></div>
+              } @empty {
+                <p class="empty">Keine Tags vergeben.</p>
+              }
+            </div>
+            <div>
+              <h3>Themen pro Größe</h3>
+              @for (entry of s.topicsPerSize; track entry.label) {
+                <div class="stat-row"><span>{{ entry.label }}</span><span>{{ entry.count }}</span></div>
+              }
+            </div>
+          </div>
+        }
+      </p-card>
+    </div>
+  `,
+  styles: [`
+    .card-header {
+      padding: 1.5rem;
+
+      h2 {
+        margin: 0;
+        display: flex;
+        align-items: center;
+        gap: 0.5rem;
+        color: var(--p-text-color);
+      }
+
+      .subtitle {
+        margin: 0.5rem 0 0 0;
+        color: var(--p-text-muted-color);
+        font-size: 0.875rem;
+      }
+    }
+
+    .stat-tiles {
+      display: grid;
+      grid-template-columns: repeat(auto-fit, minmax(160px, 1fr));
+      gap: 1rem;
+      margin-bottom: 2rem;
+    }
+
+    .stat-tile {
+      display: flex;
+      flex-direction: column;
+      gap: 0.25rem;
+      padding: 1rem;
+      border: 1px solid var(--p-content-border-color);
+      border-radius: 8px;
+
+      .value {
+        font-size: 1.75rem;
+        font-weight: 600;
+        color: var(--p-text-color);
+      }
+
+      .label {
+        font-size: 0.85rem;
+        color: var(--p-text-muted-color);
+      }
+
+      &.warning .value {
+        color: #f97316;
+      }
+    }
+
+    .stat-lists {
+      display: grid;
+      grid-template-columns: repeat(auto-fit, minmax(250px, 1fr));
+      gap: 2rem;
+
+      h3 {
+        margin: 0 0 0.75rem 0;
+        font-size: 1.1rem;
+        color: var(--p-text-color);
+      }
+    }
+
+    .stat-row {
+      display: flex;
+      justify-content: space-between;
+      padding: 0.35rem 0;
+      border-bottom: 1px solid var(--p-content-border-color);
+    }
+
+    .empty {
+      color: var(--p-text-muted-color);
+    }
+  `],
+  changeDetection: ChangeDetectionStrategy.OnPush
+})
+export class StatsComponent {
+  private readonly backend = inject(BackendService);
+  private readonly datastore = toSignal(this.backend.datastore$);
+
+  protected readonly stats = computed(() => {
+    const datastore = this.datastore();
+    return datastore ? computeDatastoreStats(datastore) : null;
+  });
+}
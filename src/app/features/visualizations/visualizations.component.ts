@@ -108,6 +108,29 @@ import { Button } from 'primeng/button';
             ></p-button>
           </ng-template>
         </p-card>
+        
+        <p-card styleClass="visualization-card">
+          <ng-template #header>
+            <div class="card-icon stats">
+              <i class="pi pi-chart-pie"></i>
+            </div>
+          </ng-template>
+          <h3>Statistik</h3>
+          <p>Kennzahlen auf einen Blick: Themen pro Tag und Größe, aktuelle Änderungen, fehlende Vertretungen und Aktualität.</p>
+          <div class="features">
+            <span><i class="pi pi-check"></i> Kennzahlen</span>
+            <span><i class="pi pi-check"></i> Vertretungslücken</span>
+            <span><i class="pi pi-check"></i> Aktualität</span>
+          </div>
+          <ng-template #footer>
+            <p-button
+              label="Öffnen"
+              icon="pi pi-arrow-right"
+              iconPos="right"
+              routerLink="/visualizations/stats"
+            ></p-button>
+          </ng-template>
+        </p-card>
       </div>
     </div>
   `,
@@ -189,6 +212,10 @@ import { Button } from 'primeng/button';
       &.load {
         background: linear-gradient(135deg, #ec4899, #f43f5e);
       }
+      
+      &.stats {
+        background: linear-gradient(135deg, #0ea5e9, #14b8a6);
+      }
     }
     
     h3 {
@@ -0,0 +1,61 @@
+import { describe, it, expect } from 'vitest';
+import { getCurrentAbsence, isMemberAbsent, isValidAbsence } from './absence.utils';
+import { MemberAbsence, TeamMember } from '../../core/models';
+
+const createMember = (absences?: MemberAbsence[]): TeamMember => ({
+  id: 'm1',
+  displayName: 'Member 1',
+  active: true,
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  absences
+});
+
+describe('absence.utils', () => {
+  describe('isValidAbsence', () => {
+    it('should accept a valid range', () => {
+      expect(isValidAbsence({ from: '2024-06-01', to: '2024-06-14', type: 'vacation' })).toBe(true);
+    });
+
+    it('should accept a single day', () => {
+      expect(isValidAbsence({ from: '2024-06-01', to: '2024-06-01', type: 'sick' })).toBe(true);
+    });
+
+    it('should reject an end before the start', () => {
+      expect(isValidAbsence({ from: '2024-06-14', to: '2024-06-01', type: 'vacation' })).toBe(false);
+    });
+
+    it('should reject invalid dates and types', () => {
+      expect(isValidAbsence({ from: '2024-02-30', to: '2024-03-01', type: 'vacation' })).toBe(false);
+      expect(isValidAbsence({ from: '', to: '2024-03-01', type: 'vacation' })).toBe(false);
+      expect(isValidAbsence({ from: '2024-03-01', to: '2024-03-01', type: 'holiday' as never })).toBe(false);
+    });
+
+    it('should reject inherited object properties as type', () => {
+      expect(isValidAbsence({ from: '2024-03-01', to: '2024-03-01', type: 'toString' as never })).toBe(false);
+      expect(isValidAbsence({ from: '2024-03-01', to: '2024-03-01', type: 'constructor' as never })).toBe(false);
+    });
+  });
+
+  describe('getCurrentAbsence', () => {
+    const vacation: MemberAbsence = { from: '2024-06-01', to: '2024-06-14', type: 'vacation' };
+
+    it('should return the absence covering the date', () => {
+      const member = createMember([vacation]);
+
+      expect(getCurrentAbsence(member, new Date(2024, 5, 5, 12))).toBe(vacation);
+    });
+
+    it('should include the whole last day', () => {
+      expect(isMemberAbsent(createMember([vacation]), new Date(2024, 5, 14, 23, 59))).toBe(true);
+      expect(isMemberAbsent(createMember([vacation]), new Date(2024, 5, 15, 0, 0))).toBe(false);
+    });
+
+    it('should return undefined before the absence starts', () => {
+      expect(getCurrentAbsence(createMember([vacation]), new Date(2024, 4, 31, 23, 59))).toBeUndefined();
+    });
+
+    it('should return undefined without absences', () => {
+      expect(isMemberAbsent(createMember(), new Date(2024, 5, 5))).toBe(false);
+    });
+  });
+});
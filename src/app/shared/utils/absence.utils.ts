@@ -0,0 +1,48 @@
+/**
+ * Member absence utilities.
+ *
+ * Absence dates are calendar dates ("YYYY-MM-DD"), both inclusive, and are
+ * evaluated in local time like topic validity dates.
+ */
+
+import { AbsenceType, MemberAbsence, TeamMember } from '../../core/models';
+import { getValidityStatus, parseValidityDate } from './validity.utils';
+
+/**
+ * Display labels for absence types.
+ */
+export const ABSENCE_TYPE_LABELS: Record<AbsenceType, string> = {
+  vacation: 'Urlaub',
+  sick: 'Krank',
+  other: 'Sonstige'
+};
+
+/**
+ * Checks whether an absence has valid dates (to not before from) and a known type.
+ */
+export function isValidAbsence(absence: MemberAbsence): boolean {
+  const from = parseValidityDate(absence.from);
+  const to = parseValidityDate(absence.to);
+  return !!from && !!to && to >= from && Object.hasOwn(ABSENCE_TYPE_LABELS, absence.type);
+}
+
+/**
+ * Returns the absence of a member covering the given point in time.
+ *
+ * @param member Team member
+ * @param now Point in time to evaluate (default: current time)
+ * @returns The active absence, or undefined if the member is present
+ */
+export function getCurrentAbsence(member: TeamMember, now: Date = new Date()): MemberAbsence | undefined {
+  return member.absences?.find(absence =>
+    isValidAbsence(absence) &&
+    getValidityStatus({ alwaysValid: false, validFrom: absence.from, validTo: absence.to }, now) === 'valid'
+  );
+}
+
+/**
+ * Checks whether a member is absent at the given point in time.
+ */
+export function isMemberAbsent(member: TeamMember, now: Date = new Date()): boolean {
+  return getCurrentAbsence(member, now) !== undefined;
+}
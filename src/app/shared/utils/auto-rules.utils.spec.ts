@@ -0,0 +1,93 @@
+import { describe, it, expect } from 'vitest';
+import { applyAutoRules, hasRuleInputChanged, replaceRuleTag, ruleMatches } from './auto-rules.utils';
+import { AutoRule, Topic } from '../../core/models';
+
+const createTopic = (overrides?: Partial<Topic>): Topic => ({
+  id: 't1',
+  header: 'Firewall Wartung',
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  ...overrides
+});
+
+const createRule = (overrides?: Partial<AutoRule>): AutoRule => ({
+  id: 'r1',
+  name: 'Netzwerk',
+  field: 'header',
+  contains: 'firewall',
+  addTags: ['Netzwerk'],
+  enabled: true,
+  ...overrides
+});
+
+describe('ruleMatches', () => {
+  it('should match case-insensitively in the selected field', () => {
+    expect(ruleMatches(createRule(), createTopic())).toBe(true);
+    expect(ruleMatches(createRule({ field: 'description' }), createTopic())).toBe(false);
+  });
+
+  it('should match keywords and all fields', () => {
+    const topic = createTopic({ header: 'Wartung', searchKeywords: ['FW', 'Firewall'] });
+
+    expect(ruleMatches(createRule({ field: 'keywords' }), topic)).toBe(true);
+    expect(ruleMatches(createRule({ field: 'any' }), topic)).toBe(true);
+  });
+
+  it('should not match disabled rules or empty conditions', () => {
+    expect(ruleMatches(createRule({ enabled: false }), createTopic())).toBe(false);
+    expect(ruleMatches(createRule({ contains: '  ' }), createTopic())).toBe(false);
+  });
+});
+
+describe('applyAutoRules', () => {
+  it('should add missing tags', () => {
+    const result = applyAutoRules(createTopic({ tags: ['IT'] }), [createRule()]);
+
+    expect(result.tags).toEqual(['IT', 'Netzwerk']);
+  });
+
+  it('should set the priority only if none is set', () => {
+    const rule = createRule({ addTags: [], setPriority: 4 });
+
+    expect(applyAutoRules(createTopic(), [rule]).priority).toBe(4);
+    expect(applyAutoRules(createTopic({ priority: 2 }), [rule]).priority).toBe(2);
+  });
+
+  it('should return the same topic if nothing changes', () => {
+    const topic = createTopic({ tags: ['Netzwerk'] });
+
+    expect(applyAutoRules(topic, [createRule(), createRule({ contains: 'drucker' })])).toBe(topic);
+  });
+});
+
+describe('hasRuleInputChanged', () => {
+  it('should detect changes of header, description and keywords', () => {
+    const topic = createTopic({ searchKeywords: ['a'] });
+
+    expect(hasRuleInputChanged(topic, { ...topic, header: 'Neu' })).toBe(true);
+    expect(hasRuleInputChanged(topic, { ...topic, description: 'Text' })).toBe(true);
+    expect(hasRuleInputChanged(topic, { ...topic, searchKeywords: ['a', 'b'] })).toBe(true);
+  });
+
+  it('should ignore other fields', () => {
+    const topic = createTopic();
+
+    expect(hasRuleInputChanged(topic, { ...topic, priority: 3, tags: ['x'] })).toBe(false);
+  });
+});
+
+describe('replaceRuleTag', () => {
+  it('should rename the tag in rule actions', () => {
+    const rules = [createRule({ addTags: ['Netzwerk', 'IT'] }), createRule({ id: 'r2', addTags: ['IT'] })];
+
+    const result = replaceRuleTag(rules, 'Netzwerk', 'Infrastruktur');
+
+    expect(result[0].addTags).toEqual(['Infrastruktur', 'IT']);
+    expect(result[1]).toBe(rules[1]);
+  });
+
+  it('should remove the tag if it was deleted', () => {
+    expect(replaceRuleTag([createRule({ addTags: ['Netzwerk', 'IT'] })], 'Netzwerk', null)[0].addTags).toEqual(['IT']);
+  });
+});
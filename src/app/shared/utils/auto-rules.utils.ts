@@ -0,0 +1,82 @@
+/**
+ * Rules for automatic tagging and field derivation of topics.
+ */
+
+import { AutoRule, AutoRuleField, Topic } from '../../core/models';
+
+export const AUTO_RULE_FIELD_LABELS: Record<AutoRuleField, string> = {
+  header: 'Thema',
+  keywords: 'Suchbegriffe',
+  description: 'Beschreibung',
+  any: 'Alle Felder'
+};
+
+function getFieldText(topic: Topic, field: AutoRuleField): string {
+  switch (field) {
+    case 'header':
+      return topic.header;
+    case 'keywords':
+      return (topic.searchKeywords ?? []).join(' ');
+    case 'description':
+      return topic.description ?? '';
+    case 'any':
+      return [topic.header, ...(topic.searchKeywords ?? []), topic.description ?? ''].join(' ');
+  }
+}
+
+/**
+ * Checks whether an enabled rule's condition matches the topic.
+ */
+export function ruleMatches(rule: AutoRule, topic: Topic): boolean {
+  const text = rule.contains.trim().toLowerCase();
+  return rule.enabled && text !== '' && getFieldText(topic, rule.field).toLowerCase().includes(text);
+}
+
+/**
+ * Applies all matching rules to a topic. Tags are added if missing; a priority is only
+ * set if the topic has none, so explicit values are never overwritten.
+ *
+ * @returns The updated topic, or the same object if no rule changed anything
+ */
+export function applyAutoRules(topic: Topic, rules: AutoRule[]): Topic {
+  let result = topic;
+  for (const rule of rules) {
+    if (!ruleMatches(rule, result)) {
+      continue;
+    }
+    const tags = result.tags ?? [];
+    const missingTags = (rule.addTags ?? []).filter(tag => !tags.includes(tag));
+    if (missingTags.length > 0) {
+      result = { ...result, tags: [...tags, ...missingTags] };
+    }
+    if (rule.setPriority !== undefined && result.priority === undefined) {
+      result = { ...result, priority: rule.setPriority };
+    }
+  }
+  return result;
+}
+
+/**
+ * Checks whether a field used in rule conditions changed, so rules need to be evaluated again.
+ */
+export function hasRuleInputChanged(before: Topic, after: Topic): boolean {
+  return before.header !== after.header
+    || (before.description ?? '') !== (after.description ?? '')
+    || (before.searchKeywords ?? []).join('\n') !== (after.searchKeywords ?? []).join('\n');
+}
+
+/**
+ * Renames a tag in the actions of all rules, or removes it if `newName` is null.
+ * Used when a managed tag is renamed or deleted.
+ */
+export function replaceRuleTag(rules: AutoRule[], oldName: string, newName: string | null): AutoRule[] {
+  return rules.map(rule => {
+    if (!rule.addTags?.includes(oldName)) {
+      return rule;
+    }
+    const addTags = newName === null
+      ? rule.addTags.filter(tag => tag !== oldName)
+      : rule.addTags.map(tag => tag === oldName ? newName : tag);
+    return { ...rule, addTags: [...new Set(addTags)] };
+  });
+}
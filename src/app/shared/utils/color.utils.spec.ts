@@ -0,0 +1,57 @@
+import { describe, it, expect } from 'vitest';
+import { COLOR_PALETTE, pickDistinctColor } from './color.utils';
+import { isValidHexColor } from './validation.utils';
+
+describe('color.utils', () => {
+  describe('COLOR_PALETTE', () => {
+    it('should contain only valid, distinct hex colors', () => {
+      expect(COLOR_PALETTE.every(color => isValidHexColor(color) && color.startsWith('#'))).toBe(true);
+      expect(new Set(COLOR_PALETTE.map(c => c.toUpperCase())).size).toBe(COLOR_PALETTE.length);
+    });
+  });
+
+  describe('pickDistinctColor', () => {
+    it('should return a palette color', () => {
+      expect(COLOR_PALETTE).toContain(pickDistinctColor('Alice', []));
+    });
+
+    it('should be deterministic for the same key', () => {
+      expect(pickDistinctColor('Alice', [])).toBe(pickDistinctColor('alice ', []));
+    });
+
+    it('should skip colors already in use', () => {
+      const first = pickDistinctColor('Alice', []);
+
+      const second = pickDistinctColor('Alice', [first]);
+
+      expect(second).not.toBe(first);
+    });
+
+    it('should compare used colors case-insensitively and without #', () => {
+      const first = pickDistinctColor('Alice', []);
+
+      const second = pickDistinctColor('Alice', [first.slice(1).toLowerCase()]);
+
+      expect(second).not.toBe(first);
+    });
+
+    it('should assign distinct colors until the palette is exhausted', () => {
+      const used: string[] = [];
+      for (let i = 0; i < COLOR_PALETTE.length; i++) {
+        used.push(pickDistinctColor(`Tag ${i}`, used));
+      }
+
+      expect(new Set(used).size).toBe(COLOR_PALETTE.length);
+    });
+
+    it('should fall back to the hashed color if all colors are in use', () => {
+      const color = pickDistinctColor('Alice', COLOR_PALETTE);
+
+      expect(color).toBe(pickDistinctColor('Alice', []));
+    });
+
+    it('should ignore invalid used colors', () => {
+      expect(pickDistinctColor('Alice', [undefined, 'nope'])).toBe(pickDistinctColor('Alice', []));
+    });
+  });
+});
@@ -0,0 +1,76 @@
+/**
+ * Color palette and automatic color assignment for members and tags.
+ *
+ * The palette consists of medium-saturated colors that stay readable with
+ * white text and are clearly distinguishable from each other.
+ */
+
+import { normalizeHexColor } from './validation.utils';
+
+/**
+ * Default palette used for automatic color assignment.
+ */
+export const COLOR_PALETTE: readonly string[] = [
+  '#1F77B4', // blue
+  '#D62728', // red
+  '#2CA02C', // green
+  '#9467BD', // purple
+  '#FF7F0E', // orange
+  '#17BECF', // cyan
+  '#8C564B', // brown
+  '#E377C2', // pink
+  '#7F7F7F', // gray
+  '#BCBD22', // olive
+  '#393B79', // indigo
+  '#AD494A', // rust
+  '#637939', // moss
+  '#7B4173', // plum
+  '#3182BD', // light blue
+  '#E6550D', // dark orange
+];
+
+/**
+ * Simple deterministic string hash (FNV-1a, 32 bit).
+ */
+function hashString(value: string): number {
+  let hash = 0x811c9dc5;
+  for (let i = 0; i < value.length; i++) {
+    hash ^= value.charCodeAt(i);
+    hash = Math.imul(hash, 0x01000193);
+  }
+  return hash >>> 0;
+}
+
+/**
+ * Picks a palette color for a new member or tag.
+ * The start position is derived from the key (e.g. the name), so the same
+ * key gets the same color. Colors already in use are skipped; if the whole
+ * palette is in use, the hashed color is returned.
+ *
+ * @param key Stable key for the hash (e.g. name)
+ * @param usedColors Colors already assigned (any case, with or without #)
+ * @param palette Palette to pick from
+ * @returns A hex color with # prefix
+ */
+export function pickDistinctColor(
+  key: string,
+  usedColors: Iterable<string | undefined>,
+  palette: readonly string[] = COLOR_PALETTE
+): string {
+  const used = new Set<string>();
+  for (const color of usedColors) {
+    const normalized = normalizeHexColor(color);
+    if (normalized) {
+      used.add(normalized.toUpperCase());
+    }
+  }
+
+  const start = hashString(key.trim().toLowerCase()) % palette.length;
+  for (let i = 0; i < palette.length; i++) {
+    const candidate = palette[(start + i) % palette.length];
+    if (!used.has(candidate.toUpperCase())) {
+      return candidate;
+    }
+  }
+  return palette[start];
+}
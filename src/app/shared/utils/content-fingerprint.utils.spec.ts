@@ -0,0 +1,28 @@
+import { describe, it, expect } from 'vitest';
+import { createContentFingerprint } from './content-fingerprint.utils';
+
+describe('createContentFingerprint', () => {
+  it('should ignore case, punctuation and whitespace', () => {
+    expect(createContentFingerprint({ header: 'Urlaubsantrag', description: 'Antrag  stellen.' }))
+      .toBe(createContentFingerprint({ header: 'urlaubsantrag!', description: 'antrag stellen' }));
+  });
+
+  it('should treat diacritics and HTML markup as equal', () => {
+    expect(createContentFingerprint({ header: 'Bürgeramt', description: '<p>Öffnungszeiten</p>' }))
+      .toBe(createContentFingerprint({ header: 'Burgeramt', description: 'Offnungszeiten' }));
+  });
+
+  it('should keep placeholders in angle brackets', () => {
+    expect(createContentFingerprint({ header: 'Ablage', description: 'Unter <Abteilung> ablegen' }))
+      .not.toBe(createContentFingerprint({ header: 'Ablage', description: 'Unter ablegen' }));
+  });
+
+  it('should differ for different content', () => {
+    expect(createContentFingerprint({ header: 'Urlaubsantrag', description: 'Antrag stellen' }))
+      .not.toBe(createContentFingerprint({ header: 'Urlaubsantrag', description: 'Antrag prüfen' }));
+  });
+
+  it('should handle a missing description', () => {
+    expect(createContentFingerprint({ header: 'Urlaub' })).toBe('urlaub|');
+  });
+});
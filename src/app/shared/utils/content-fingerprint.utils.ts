@@ -0,0 +1,24 @@
+/**
+ * Content fingerprints for detecting near-identical topics, e.g. copies that
+ * only differ in case, punctuation, diacritics or HTML markup.
+ */
+
+import { Topic } from '../../core/models';
+import { normalizeForPhraseMatch } from '../../core/services/search-query';
+import { sanitizeDescription } from './description.utils';
+
+/**
+ * Normalizes text for comparison: pasted HTML is converted to plain text, then
+ * the text is folded the same way as search phrases.
+ */
+function normalizeContent(text: string): string {
+  return normalizeForPhraseMatch(sanitizeDescription(text));
+}
+
+/**
+ * Returns the content fingerprint of a topic, built from its normalized header
+ * and description. Topics with the same fingerprint are considered duplicates.
+ */
+export function createContentFingerprint(topic: Pick<Topic, 'header' | 'description'>): string {
+  return `${normalizeContent(topic.header)}|${normalizeContent(topic.description ?? '')}`;
+}
@@ -0,0 +1,67 @@
+import { describe, it, expect } from 'vitest';
+import { computeDatastoreStats } from './datastore-stats.utils';
+import { Datastore, Topic } from '../../core/models';
+
+const NOW = new Date('2024-06-30T12:00:00.000Z');
+
+const createTopic = (id: string, overrides?: Partial<Topic>): Topic => ({
+  id,
+  header: `Topic ${id}`,
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-06-28T12:00:00.000Z',
+  ...overrides
+});
+
+const createDatastore = (topics: Topic[]): Datastore => ({
+  schemaVersion: 1,
+  generatedAt: NOW.toISOString(),
+  revisionId: 1,
+  members: [{ id: 'm1', displayName: 'Anna', active: true, updatedAt: NOW.toISOString() }],
+  topics,
+  tags: []
+});
+
+describe('computeDatastoreStats', () => {
+  it('should count entities and topics per tag', () => {
+    const stats = computeDatastoreStats(createDatastore([
+      createTopic('t1', { tags: ['IT', 'HR'] }),
+      createTopic('t2', { tags: ['IT'] })
+    ]), NOW);
+
+    expect(stats.topicCount).toBe(2);
+    expect(stats.memberCount).toBe(1);
+    expect(stats.topicsPerTag).toEqual([{ label: 'IT', count: 2 }, { label: 'HR', count: 1 }]);
+  });
+
+  it('should count topics per size in size order', () => {
+    const stats = computeDatastoreStats(createDatastore([
+      createTopic('t1', { size: 'L' }),
+      createTopic('t2', { size: 'S' }),
+      createTopic('t3')
+    ]), NOW);
+
+    expect(stats.topicsPerSize.filter(entry => entry.count > 0)).toEqual([
+      { label: 'S', count: 1 },
+      { label: 'L', count: 1 },
+      { label: 'Ohne Größe', count: 1 }
+    ]);
+  });
+
+  it('should compute recent changes, staleness and missing R2', () => {
+    const stats = computeDatastoreStats(createDatastore([
+      createTopic('t1', { updatedAt: '2024-06-28T12:00:00.000Z' }),
+      createTopic('t2', { updatedAt: '2024-06-10T12:00:00.000Z', raci: { r1MemberId: 'm1', r2MemberId: 'm1', cMemberIds: [], iMemberIds: [] } }),
+      createTopic('t3', { updatedAt: '2024-03-02T12:00:00.000Z' })
+    ]), NOW);
+
+    expect(stats.modifiedLast7Days).toBe(1);
+    expect(stats.modifiedLast30Days).toBe(2);
+    expect(stats.topicsWithoutR2).toBe(2);
+    expect(stats.averageStalenessDays).toBe(47);
+  });
+
+  it('should return no staleness without topics', () => {
+    expect(computeDatastoreStats(createDatastore([]), NOW).averageStalenessDays).toBeNull();
+  });
+});
@@ -0,0 +1,70 @@
+/**
+ * Aggregate statistics over the loaded datastore for the statistics overview.
+ */
+
+import { Datastore, TShirtSize } from '../../core/models';
+
+const SIZES: TShirtSize[] = ['XXS', 'XS', 'S', 'M', 'L', 'XL', 'XXL'];
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+export interface CountEntry {
+  label: string;
+  count: number;
+}
+
+export interface DatastoreStats {
+  topicCount: number;
+  memberCount: number;
+  tagCount: number;
+  /** Topics per tag, most used first */
+  topicsPerTag: CountEntry[];
+  /** Topics per size in size order; topics without size are listed last as "Ohne Größe" */
+  topicsPerSize: CountEntry[];
+  modifiedLast7Days: number;
+  modifiedLast30Days: number;
+  /** Topics without a second responsible (R2), i.e. no substitute */
+  topicsWithoutR2: number;
+  /** Average number of days since the last change of a topic, null without topics */
+  averageStalenessDays: number | null;
+}
+
+/**
+ * Computes the statistics of a datastore. Timestamps that cannot be parsed are ignored
+ * for the time-based figures.
+ */
+export function computeDatastoreStats(datastore: Datastore, now: Date = new Date()): DatastoreStats {
+  const topics = datastore.topics;
+
+  const tagCounts = new Map<string, number>();
+  for (const topic of topics) {
+    for (const tag of new Set(topic.tags ?? [])) {
+      tagCounts.set(tag, (tagCounts.get(tag) ?? 0) + 1);
+    }
+  }
+  const topicsPerTag = Array.from(tagCounts, ([label, count]) => ({ label, count }))
+    .sort((a, b) => b.count - a.count || a.label.localeCompare(b.label));
+
+  const topicsPerSize = SIZES.map(size => ({ label: size, count: topics.filter(topic => topic.size === size).length }));
+  const withoutSize = topics.filter(topic => !topic.size).length;
+  if (withoutSize > 0) {
+    topicsPerSize.push({ label: 'Ohne Größe', count: withoutSize });
+  }
+
+  const ages = topics
+    .map(topic => (now.getTime() - Date.parse(topic.updatedAt)) / DAY_MS)
+    .filter(age => !Number.isNaN(age));
+
+  return {
+    topicCount: topics.length,
+    memberCount: datastore.members.length,
+    tagCount: (datastore.tags ?? []).length,
+    topicsPerTag,
+    topicsPerSize,
+    modifiedLast7Days: ages.filter(age => age <= 7).length,
+    modifiedLast30Days: ages.filter(age => age <= 30).length,
+    topicsWithoutR2: topics.filter(topic => !topic.raci.r2MemberId).length,
+    averageStalenessDays: ages.length > 0
+      ? Math.round(ages.reduce((sum, age) => sum + Math.max(age, 0), 0) / ages.length)
+      : null
+  };
+}
@@ -0,0 +1,76 @@
+import { describe, it, expect } from 'vitest';
+import { extractLinks, normalizeTopicDescription, sanitizeDescription } from './description.utils';
+import { Topic } from '../../core/models';
+
+const createTopic = (overrides?: Partial<Topic>): Topic => ({
+  id: 't1',
+  header: 'Topic',
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  ...overrides
+});
+
+describe('sanitizeDescription', () => {
+  it('should return plain text unchanged', () => {
+    const text = 'Preis < 5 & Menge > 2\n\nZweiter Absatz';
+
+    expect(sanitizeDescription(text)).toBe(text);
+  });
+
+  it('should keep angle-bracket placeholders', () => {
+    const text = 'Mail an <Vorname.Nachname>@example.de\nAblage: \\\\server\\<Abteilung>\\Akten';
+
+    expect(sanitizeDescription(text)).toBe(text);
+    expect(sanitizeDescription('<p>Mail an <Vorname.Nachname></p>')).toBe('Mail an <Vorname.Nachname>');
+  });
+
+  it('should convert paragraphs and line breaks to newlines', () => {
+    expect(sanitizeDescription('<p>Erste Zeile<br>Zweite</p><p>Absatz &amp; mehr</p>')).toBe('Erste Zeile\nZweite\nAbsatz & mehr');
+  });
+
+  it('should keep link targets', () => {
+    expect(sanitizeDescription('Siehe <a href="https://wiki/x">Wiki</a> und <a href="https://a.de">https://a.de</a>'))
+      .toBe('Siehe Wiki (https://wiki/x) und https://a.de');
+  });
+
+  it('should drop scripts and styles', () => {
+    expect(sanitizeDescription('<div>Text<script>alert(1)</script><style>p{}</style></div>')).toBe('Text');
+  });
+});
+
+describe('extractLinks', () => {
+  it('should extract unique links without trailing punctuation', () => {
+    expect(extractLinks('Siehe https://wiki.example/a, (http://b.example/x) und https://wiki.example/a.'))
+      .toEqual(['https://wiki.example/a', 'http://b.example/x']);
+  });
+
+  it('should prefix www links with https', () => {
+    expect(extractLinks('Infos: www.example.de/seite')).toEqual(['https://www.example.de/seite']);
+  });
+
+  it('should return an empty list without links', () => {
+    expect(extractLinks('Kein Link')).toEqual([]);
+  });
+});
+
+describe('normalizeTopicDescription', () => {
+  it('should sanitize the description and set links', () => {
+    const result = normalizeTopicDescription(createTopic({ description: '<p>Doku: https://doku.example</p>' }));
+
+    expect(result.description).toBe('Doku: https://doku.example');
+    expect(result.links).toEqual(['https://doku.example']);
+  });
+
+  it('should remove links that are no longer in the description', () => {
+    const result = normalizeTopicDescription(createTopic({ description: 'Ohne Link', links: ['https://old.example'] }));
+
+    expect(result.links).toBeUndefined();
+  });
+
+  it('should return the same topic if nothing changes', () => {
+    const topic = createTopic({ description: 'Siehe https://a.example', links: ['https://a.example'] });
+
+    expect(normalizeTopicDescription(topic)).toBe(topic);
+  });
+});
@@ -0,0 +1,87 @@
+/**
+ * Cleanup of topic descriptions: pasted HTML is converted to plain text and
+ * outbound links are extracted into a separate field.
+ */
+
+import { Topic } from '../../core/models';
+
+/**
+ * Elements produced by pasting from browsers, Word or Outlook. Other `<...>` text
+ * such as `<Vorname.Nachname>` or `\\server\<Abteilung>` is a placeholder, not HTML.
+ */
+const HTML_ELEMENTS = new Set([
+  'a', 'b', 'br', 'div', 'em', 'font', 'h1', 'h2', 'h3', 'h4', 'h5', 'h6', 'i', 'img',
+  'li', 'ol', 'p', 'script', 'span', 'strong', 'style', 'table', 'tbody', 'td', 'th',
+  'thead', 'tr', 'u', 'ul'
+]);
+const TAG_PATTERN = /<\/?([a-z][a-z0-9]*)(?=[\s/>])[^<>]*>/gi;
+const LINK_PATTERN = /\b(?:https?:\/\/|www\.)[^\s<>"'`]+/gi;
+const TRAILING_PUNCTUATION = /[.,;:!?)\]}]+$/;
+
+const HTML_ENTITIES: Record<string, string> = {
+  '&amp;': '&',
+  '&lt;': '<',
+  '&gt;': '>',
+  '&quot;': '"',
+  '&#39;': "'",
+  '&nbsp;': ' '
+};
+
+function containsHtml(text: string): boolean {
+  return Array.from(text.matchAll(TAG_PATTERN)).some(([, name]) => HTML_ELEMENTS.has(name.toLowerCase()));
+}
+
+function stripHtmlTags(text: string): string {
+  return text.replace(TAG_PATTERN, (tag: string, name: string) => HTML_ELEMENTS.has(name.toLowerCase()) ? '' : tag);
+}
+
+/**
+ * Converts pasted HTML to plain text. Line breaks and paragraphs become newlines,
+ * links keep their target as "label (url)". Only known HTML elements are removed;
+ * text without them (including `<placeholder>` text) is returned unchanged.
+ */
+export function sanitizeDescription(text: string): string {
+  if (!containsHtml(text)) {
+    return text;
+  }
+  const withoutMarkup = text
+    .replace(/<(script|style)[^>]*>[\s\S]*?<\/\1>/gi, '')
+    .replace(/<a\s[^>]*href\s*=\s*["']([^"']+)["'][^>]*>([\s\S]*?)<\/a>/gi, (_, href: string, label: string) => {
+      const labelText = stripHtmlTags(label).trim();
+      return labelText && labelText !== href ? `${labelText} (${href})` : href;
+    })
+    .replace(/<br\s*\/?>/gi, '\n')
+    .replace(/<\/(p|div|li|h[1-6]|tr)>/gi, '\n');
+  return stripHtmlTags(withoutMarkup)
+    .replace(/&(amp|lt|gt|quot|#39|nbsp);/g, entity => HTML_ENTITIES[entity])
+    .replace(/[ \t]+\n/g, '\n')
+    .replace(/\n{3,}/g, '\n\n')
+    .trim();
+}
+
+/**
+ * Extracts unique http(s) and www links from a text, in order of appearance.
+ */
+export function extractLinks(text: string): string[] {
+  const links = (text.match(LINK_PATTERN) ?? [])
+    .map(link => link.replace(TRAILING_PUNCTUATION, ''))
+    .map(link => link.toLowerCase().startsWith('www.') ? `https://${link}` : link);
+  return [...new Set(links)];
+}
+
+/**
+ * Sanitizes the description of a topic and updates its extracted links.
+ *
+ * @returns The updated topic, or the same object if nothing changed
+ */
+export function normalizeTopicDescription(topic: Topic): Topic {
+  const description = topic.description ? sanitizeDescription(topic.description) : topic.description;
+  const links = description ? extractLinks(description) : [];
+  const currentLinks = topic.links ?? [];
+  const linksChanged = links.length !== currentLinks.length || links.some((link, i) => link !== currentLinks[i]);
+
+  if (description === topic.description && !linksChanged) {
+    return topic;
+  }
+  return { ...topic, description, links: links.length > 0 ? links : undefined };
+}
@@ -0,0 +1,53 @@
+import { describe, it, expect } from 'vitest';
+import { formatFileNumber, getFileNumberError, isValidFileNumberPattern } from './file-number.utils';
+import { Topic } from '../../core/models';
+
+const createTopic = (id: string, fileNumber: string): Topic => ({
+  id,
+  header: `Topic ${id}`,
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  hasFileNumber: true,
+  fileNumber
+});
+
+describe('formatFileNumber', () => {
+  it('should normalize whitespace and separators', () => {
+    expect(formatFileNumber(' az  12 – 3 / 2024 ')).toBe('az 12-3/2024');
+  });
+
+  it('should convert to upper case if configured', () => {
+    expect(formatFileNumber('az 12.3', { fileNumberUppercase: true })).toBe('AZ 12.3');
+  });
+});
+
+describe('isValidFileNumberPattern', () => {
+  it('should detect invalid regular expressions', () => {
+    expect(isValidFileNumberPattern('[A-Z]{2} \\d+')).toBe(true);
+    expect(isValidFileNumberPattern('[A-Z')).toBe(false);
+  });
+});
+
+describe('getFileNumberError', () => {
+  const policy = { fileNumberPattern: '[A-Z]{2} \\d+-\\d+', fileNumberUppercase: true, fileNumberUnique: true };
+
+  it('should accept matching file numbers after formatting', () => {
+    expect(getFileNumberError('az 12 - 3', policy, [], 't1')).toBe('');
+  });
+
+  it('should require the pattern to match the whole value', () => {
+    expect(getFileNumberError('AZ 12-3 alt', policy, [], 't1')).toContain('Format');
+  });
+
+  it('should report duplicates in other topics', () => {
+    const topics = [createTopic('t1', 'AZ 12-3'), createTopic('t2', 'az 12-3')];
+
+    expect(getFileNumberError('AZ 12-3', policy, topics, 't1')).toContain('Topic t2');
+    expect(getFileNumberError('AZ 12-4', policy, topics, 't1')).toBe('');
+  });
+
+  it('should accept everything without a policy', () => {
+    expect(getFileNumberError('beliebig', undefined, [], 't1')).toBe('');
+  });
+});
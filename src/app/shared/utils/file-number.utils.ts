@@ -0,0 +1,55 @@
+/**
+ * Formatting and validation of file numbers (Aktenzeichen) according to the workspace policy.
+ */
+
+import { ReferencePolicy, Topic } from '../../core/models';
+
+/**
+ * Normalizes a file number: trims, collapses whitespace, removes spaces around separators
+ * (- / . :), replaces typographic dashes and optionally converts to upper case.
+ * Example: " az 12 – 3 / 2024 " → "AZ 12-3/2024".
+ */
+export function formatFileNumber(value: string, policy?: ReferencePolicy): string {
+  const formatted = value
+    .trim()
+    .replace(/[\u2010-\u2015]/g, '-')
+    .replace(/\s+/g, ' ')
+    .replace(/\s*([-/.:])\s*/g, '$1');
+  return policy?.fileNumberUppercase ? formatted.toUpperCase() : formatted;
+}
+
+/**
+ * Checks whether the pattern is a valid regular expression.
+ */
+export function isValidFileNumberPattern(pattern: string): boolean {
+  try {
+    new RegExp(pattern);
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * Returns a German error message if the formatted file number violates the policy, or an empty string.
+ * The pattern must match the whole file number; uniqueness is checked case-insensitively
+ * against all other topics with a file number.
+ */
+export function getFileNumberError(value: string, policy: ReferencePolicy | undefined, topics: Topic[], topicId: string): string {
+  const formatted = formatFileNumber(value, policy);
+  if (!formatted || !policy) {
+    return '';
+  }
+  if (policy.fileNumberPattern && isValidFileNumberPattern(policy.fileNumberPattern)
+    && !new RegExp(`^(?:${policy.fileNumberPattern})$`).test(formatted)) {
+    return `Aktenzeichen entspricht nicht dem vorgegebenen Format (${policy.fileNumberPattern}).`;
+  }
+  if (policy.fileNumberUnique) {
+    const duplicate = topics.find(topic => topic.id !== topicId && topic.hasFileNumber && topic.fileNumber
+      && formatFileNumber(topic.fileNumber, policy).toLowerCase() === formatted.toLowerCase());
+    if (duplicate) {
+      return `Aktenzeichen wird bereits von „${duplicate.header}“ verwendet.`;
+    }
+  }
+  return '';
+}
@@ -0,0 +1,69 @@
+import { describe, it, expect } from 'vitest';
+import { getSharedPathError, isAllowedSharedPath, isValidSharedPath, normalizeSharedPath } from './file-path.utils';
+
+describe('normalizeSharedPath', () => {
+  it('should keep a normalized UNC path', () => {
+    expect(normalizeSharedPath('\\\\server\\share\\ordner')).toBe('\\\\server\\share\\ordner');
+  });
+
+  it('should convert slashes and remove duplicate separators', () => {
+    expect(normalizeSharedPath(' //server/share//ordner/ ')).toBe('\\\\server\\share\\ordner');
+  });
+
+  it('should convert file URLs', () => {
+    expect(normalizeSharedPath('file://server/share/Mein%20Ordner')).toBe('\\\\server\\share\\Mein Ordner');
+    expect(normalizeSharedPath('file:////server/share')).toBe('\\\\server\\share');
+    expect(normalizeSharedPath('file:///C:/Daten/')).toBe('C:\\Daten');
+  });
+
+  it('should strip surrounding quotes', () => {
+    expect(normalizeSharedPath('"C:\\Daten\\Projekt"')).toBe('C:\\Daten\\Projekt');
+  });
+
+  it('should keep a drive root', () => {
+    expect(normalizeSharedPath('C:\\')).toBe('C:\\');
+  });
+});
+
+describe('isValidSharedPath', () => {
+  it('should accept UNC and drive paths', () => {
+    expect(isValidSharedPath('\\\\server\\share')).toBe(true);
+    expect(isValidSharedPath('\\\\server\\share\\a\\b')).toBe(true);
+    expect(isValidSharedPath('D:\\Daten')).toBe(true);
+  });
+
+  it('should reject incomplete or invalid paths', () => {
+    expect(isValidSharedPath('\\\\server')).toBe(false);
+    expect(isValidSharedPath('ordner\\datei')).toBe(false);
+    expect(isValidSharedPath('C:\\a?b')).toBe(false);
+  });
+});
+
+describe('isAllowedSharedPath', () => {
+  const prefixes = ['\\\\fs01\\abteilung', 'file://fs02/archiv'];
+
+  it('should allow every path without prefixes', () => {
+    expect(isAllowedSharedPath('C:\\x', [])).toBe(true);
+  });
+
+  it('should match prefixes case-insensitively and normalized', () => {
+    expect(isAllowedSharedPath('\\\\FS01\\Abteilung\\Team', prefixes)).toBe(true);
+    expect(isAllowedSharedPath('//fs02/archiv', prefixes)).toBe(true);
+  });
+
+  it('should only match whole path segments', () => {
+    expect(isAllowedSharedPath('\\\\fs01\\abteilung-alt', prefixes)).toBe(false);
+  });
+});
+
+describe('getSharedPathError', () => {
+  it('should return no error for empty or valid paths', () => {
+    expect(getSharedPathError('', undefined)).toBe('');
+    expect(getSharedPathError('//server/share', undefined)).toBe('');
+  });
+
+  it('should report invalid and not allowed paths', () => {
+    expect(getSharedPathError('ordner', undefined)).toContain('Ungültiger Pfad');
+    expect(getSharedPathError('\\\\other\\share', ['\\\\fs01\\share'])).toContain('außerhalb');
+  });
+});
@@ -0,0 +1,79 @@
+/**
+ * Validation and normalization of shared file paths (UNC, drive letters and file:// URLs).
+ */
+
+const INVALID_PATH_CHARS = /[<>"|?*]/;
+const UNC_PATTERN = /^\\\\[^\\/:]+\\[^\\/:]+(\\.*)?$/;
+const DRIVE_PATTERN = /^[A-Za-z]:\\/;
+
+/**
+ * Normalizes a shared path to Windows notation:
+ * file:// URLs are converted, slashes become backslashes and duplicate separators are removed.
+ * Examples: "file://server/share/x" → "\\server\share\x", "file:///C:/Daten/" → "C:\Daten".
+ */
+export function normalizeSharedPath(path: string): string {
+  let result = path.trim().replace(/^"(.*)"$/, '$1').trim();
+  if (/^file:/i.test(result)) {
+    result = result.replace(/^file:\/*/i, '');
+    try {
+      result = decodeURIComponent(result);
+    } catch {
+      // Keep undecodable sequences as they are
+    }
+    // file:///C:/... refers to a local drive, everything else to a server
+    result = /^[A-Za-z]:/.test(result) ? result : `\\\\${result}`;
+  }
+  result = result.replace(/\//g, '\\');
+  const isUnc = result.startsWith('\\\\');
+  result = result.replace(/\\{2,}/g, '\\');
+  if (isUnc) {
+    result = `\\${result}`;
+  }
+  if (result.length > 3 && result.endsWith('\\')) {
+    result = result.slice(0, -1);
+  }
+  return result;
+}
+
+/**
+ * Checks whether a normalized path is a UNC path (\\server\share) or a drive path (C:\...).
+ */
+export function isValidSharedPath(path: string): boolean {
+  if (INVALID_PATH_CHARS.test(path)) {
+    return false;
+  }
+  return UNC_PATTERN.test(path) || DRIVE_PATTERN.test(path);
+}
+
+/**
+ * Checks whether a path starts with one of the allowed prefixes (case-insensitive).
+ * Prefixes match whole path segments only. Without prefixes, every path is allowed.
+ */
+export function isAllowedSharedPath(path: string, prefixes: string[] | undefined): boolean {
+  if (!prefixes || prefixes.length === 0) {
+    return true;
+  }
+  const normalizedPath = normalizeSharedPath(path).toLowerCase();
+  return prefixes.some(prefix => {
+    const normalizedPrefix = normalizeSharedPath(prefix).toLowerCase();
+    return normalizedPath === normalizedPrefix
+      || normalizedPath.startsWith(normalizedPrefix.endsWith('\\') ? normalizedPrefix : `${normalizedPrefix}\\`);
+  });
+}
+
+/**
+ * Returns a German error message for an invalid or not allowed path, or an empty string.
+ */
+export function getSharedPathError(path: string, prefixes: string[] | undefined): string {
+  const normalized = normalizeSharedPath(path);
+  if (!normalized) {
+    return '';
+  }
+  if (!isValidSharedPath(normalized)) {
+    return 'Ungültiger Pfad. Erwartet wird z.B. \\\\server\\freigabe\\ordner oder C:\\ordner.';
+  }
+  if (!isAllowedSharedPath(normalized, prefixes)) {
+    return `Pfad liegt außerhalb der erlaubten Ablageorte (${prefixes!.join(', ')}).`;
+  }
+  return '';
+}
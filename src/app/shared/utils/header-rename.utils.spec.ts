@@ -0,0 +1,74 @@
+import { describe, it, expect } from 'vitest';
+import { previewHeaderRename } from './header-rename.utils';
+import { Topic } from '../../core/models';
+
+const createTopic = (id: string, header: string): Topic => ({
+  id,
+  header,
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z'
+});
+
+const plain = { useRegex: false, caseSensitive: false };
+
+describe('previewHeaderRename', () => {
+  const topics = [
+    createTopic('t1', 'IT-Abteilung: Backup'),
+    createTopic('t2', 'Drucker (it-abteilung)'),
+    createTopic('t3', 'Urlaubsplanung')
+  ];
+
+  it('should replace plain text case-insensitively', () => {
+    const preview = previewHeaderRename(topics, 'IT-Abteilung', 'Digital Services', plain);
+
+    expect(preview.changes).toEqual([
+      { topicId: 't1', before: 'IT-Abteilung: Backup', after: 'Digital Services: Backup' },
+      { topicId: 't2', before: 'Drucker (it-abteilung)', after: 'Drucker (Digital Services)' }
+    ]);
+    expect(preview.error).toBe('');
+  });
+
+  it('should respect case sensitivity', () => {
+    const preview = previewHeaderRename(topics, 'IT-Abteilung', 'DS', { useRegex: false, caseSensitive: true });
+
+    expect(preview.changes.map(c => c.topicId)).toEqual(['t1']);
+  });
+
+  it('should treat regex characters literally in plain mode', () => {
+    const preview = previewHeaderRename(topics, '(it-abteilung)', '$1', plain);
+
+    expect(preview.changes).toEqual([{ topicId: 't2', before: 'Drucker (it-abteilung)', after: 'Drucker $1' }]);
+  });
+
+  it('should support regex groups', () => {
+    const preview = previewHeaderRename(topics, '^(\\w+)planung$', '$1-Planung', { useRegex: true, caseSensitive: true });
+
+    expect(preview.changes).toEqual([{ topicId: 't3', before: 'Urlaubsplanung', after: 'Urlaubs-Planung' }]);
+  });
+
+  it('should reject changes that leave an empty header', () => {
+    const preview = previewHeaderRename(topics, 'Urlaubsplanung', '', plain);
+
+    expect(preview.changes).toEqual([]);
+    expect(preview.rejected.map(c => c.topicId)).toEqual(['t3']);
+  });
+
+  it('should report invalid regular expressions', () => {
+    const preview = previewHeaderRename(topics, '(', 'x', { useRegex: true, caseSensitive: false });
+
+    expect(preview.error).toBe('Ungültiger regulärer Ausdruck');
+    expect(preview.changes).toEqual([]);
+  });
+
+  it('should not list headers the pattern does not match', () => {
+    const preview = previewHeaderRename([createTopic('t1', 'Foo  Bar ')], 'Baz', 'Qux', plain);
+
+    expect(preview.changes).toEqual([]);
+    expect(preview.rejected).toEqual([]);
+  });
+
+  it('should return no changes for an empty pattern', () => {
+    expect(previewHeaderRename(topics, '', 'x', plain).changes).toEqual([]);
+  });
+});
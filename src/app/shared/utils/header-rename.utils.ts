@@ -0,0 +1,71 @@
+/**
+ * Find-and-replace across topic headers (e.g. after a department rename).
+ */
+
+import { Topic } from '../../core/models';
+
+export interface HeaderRenameChange {
+  topicId: string;
+  before: string;
+  after: string;
+}
+
+export interface HeaderRenameOptions {
+  useRegex: boolean;
+  caseSensitive: boolean;
+}
+
+export interface HeaderRenamePreview {
+  /** Headers that would change */
+  changes: HeaderRenameChange[];
+  /** Changes that would leave an empty header and are not applied */
+  rejected: HeaderRenameChange[];
+  /** Error message for an invalid pattern, empty if valid */
+  error: string;
+}
+
+function escapeRegExp(text: string): string {
+  return text.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+}
+
+/**
+ * Computes which topic headers change when replacing the pattern (dry run).
+ * Plain patterns are matched literally; with useRegex the replacement may use $1, $2, ...
+ */
+export function previewHeaderRename(
+  topics: Topic[],
+  pattern: string,
+  replacement: string,
+  options: HeaderRenameOptions
+): HeaderRenamePreview {
+  const preview: HeaderRenamePreview = { changes: [], rejected: [], error: '' };
+  if (!pattern) {
+    return preview;
+  }
+
+  let regex: RegExp;
+  try {
+    regex = new RegExp(options.useRegex ? pattern : escapeRegExp(pattern), options.caseSensitive ? 'g' : 'gi');
+  } catch {
+    return { ...preview, error: 'Ungültiger regulärer Ausdruck' };
+  }
+  // Literal replacement for plain patterns, so "$" in the replacement is not interpreted
+  const replace = (header: string) => options.useRegex
+    ? header.replace(regex, replacement)
+    : header.replace(regex, () => replacement);
+
+  for (const topic of topics) {
+    // Compare before whitespace cleanup, so only headers the pattern matched are listed
+    const replaced = replace(topic.header);
+    if (replaced === topic.header) {
+      continue;
+    }
+    const after = replaced.replace(/\s+/g, ' ').trim();
+    if (after === topic.header) {
+      continue;
+    }
+    const change = { topicId: topic.id, before: topic.header, after };
+    (after ? preview.changes : preview.rejected).push(change);
+  }
+  return preview;
+}
@@ -0,0 +1,45 @@
+import { describe, it, expect } from 'vitest';
+import { extractTerms, suggestKeywords } from './keyword-suggestion.utils';
+import { Topic } from '../../core/models';
+
+const createTopic = (id: string, header: string, overrides?: Partial<Topic>): Topic => ({
+  id,
+  header,
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  ...overrides
+});
+
+describe('extractTerms', () => {
+  it('should drop stop words, numbers and short words', () => {
+    expect(extractTerms('Die Wartung der Firewall ab 2024 im RZ')).toEqual(['wartung', 'firewall']);
+  });
+
+  it('should keep terms with inner separators', () => {
+    expect(extractTerms('E-Mail über Outlook.com')).toEqual(['e-mail', 'outlook.com']);
+  });
+});
+
+describe('suggestKeywords', () => {
+  const corpus = [
+    createTopic('t1', 'Wartung Firewall', { description: 'Regelwerk der Firewall prüfen, Wartung monatlich' }),
+    createTopic('t2', 'Wartung Drucker'),
+    createTopic('t3', 'Wartung Server')
+  ];
+
+  it('should rank terms that are rare in the corpus first', () => {
+    expect(suggestKeywords(corpus[0], corpus, 2)).toEqual(['firewall', 'wartung']);
+  });
+
+  it('should not suggest existing keywords or tags', () => {
+    const topic = { ...corpus[0], searchKeywords: ['Firewall'], tags: ['wartung'] };
+
+    expect(suggestKeywords(topic, corpus, 3)).not.toContain('firewall');
+    expect(suggestKeywords(topic, corpus, 3)).not.toContain('wartung');
+  });
+
+  it('should work for unsaved topics', () => {
+    expect(suggestKeywords(createTopic('', 'Druckertreiber verteilen'), corpus)).toEqual(['druckertreiber', 'verteilen']);
+  });
+});
@@ -0,0 +1,70 @@
+/**
+ * Suggests search keywords for a topic from its header, description and notes.
+ * Terms are ranked by TF-IDF against all topics, so words that are frequent in the
+ * topic but rare in the corpus come first.
+ */
+
+import { Topic } from '../../core/models';
+import { GERMAN_STOP_WORDS } from './stop-words.utils';
+
+const MIN_TERM_LENGTH = 3;
+const HEADER_WEIGHT = 2;
+
+/**
+ * Splits text into lower-case word terms, dropping stop words, numbers and short words.
+ */
+export function extractTerms(text: string, stopWords: ReadonlySet<string> = GERMAN_STOP_WORDS): string[] {
+  return (text.toLowerCase().match(/[\p{L}\p{N}][\p{L}\p{N}_.-]*[\p{L}\p{N}]|[\p{L}\p{N}]/gu) ?? [])
+    .filter(term => term.length >= MIN_TERM_LENGTH && !/^[\d.-]+$/.test(term) && !stopWords.has(term));
+}
+
+function getTopicTerms(topic: Topic, stopWords: ReadonlySet<string>): Map<string, number> {
+  const counts = new Map<string, number>();
+  const add = (text: string | undefined, weight: number) => {
+    for (const term of extractTerms(text ?? '', stopWords)) {
+      counts.set(term, (counts.get(term) ?? 0) + weight);
+    }
+  };
+  add(topic.header, HEADER_WEIGHT);
+  add(topic.description, 1);
+  add(topic.notes, 1);
+  return counts;
+}
+
+/**
+ * Returns up to `limit` keyword suggestions for the topic, best first.
+ * Existing keywords and tags of the topic are not suggested.
+ *
+ * @param topic The topic to suggest keywords for (may be unsaved)
+ * @param corpus All topics, used for the document frequency of terms
+ */
+export function suggestKeywords(
+  topic: Topic,
+  corpus: Topic[],
+  limit = 5,
+  stopWords: ReadonlySet<string> = GERMAN_STOP_WORDS
+): string[] {
+  const termCounts = getTopicTerms(topic, stopWords);
+  const existing = new Set([...(topic.searchKeywords ?? []), ...(topic.tags ?? [])].map(k => k.toLowerCase()));
+
+  const documentFrequency = new Map<string, number>();
+  const others = corpus.filter(t => t.id !== topic.id || !topic.id);
+  for (const other of others) {
+    for (const term of getTopicTerms(other, stopWords).keys()) {
+      if (termCounts.has(term)) {
+        documentFrequency.set(term, (documentFrequency.get(term) ?? 0) + 1);
+      }
+    }
+  }
+
+  const documentCount = others.length + 1;
+  return [...termCounts.entries()]
+    .filter(([term]) => !existing.has(term))
+    .map(([term, count]) => ({
+      term,
+      score: count * (Math.log(documentCount / (1 + (documentFrequency.get(term) ?? 0))) + 1)
+    }))
+    .sort((a, b) => b.score - a.score || a.term.localeCompare(b.term))
+    .slice(0, limit)
+    .map(entry => entry.term);
+}
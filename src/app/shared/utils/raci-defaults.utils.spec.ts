@@ -0,0 +1,38 @@
+import { describe, it, expect } from 'vitest';
+import { applyRaciDefaults } from './raci-defaults.utils';
+import { TopicRaci } from '../../core/models';
+
+const createRaci = (overrides?: Partial<TopicRaci>): TopicRaci => ({
+  r1MemberId: 'm1',
+  cMemberIds: [],
+  iMemberIds: [],
+  ...overrides
+});
+
+describe('applyRaciDefaults', () => {
+  it('should add default C and I members', () => {
+    const result = applyRaciDefaults(createRaci({ iMemberIds: ['m2'] }), { cMemberIds: ['qa'], iMemberIds: ['head'] });
+
+    expect(result.cMemberIds).toEqual(['qa']);
+    expect(result.iMemberIds).toEqual(['m2', 'head']);
+  });
+
+  it('should skip members that already have a role', () => {
+    const raci = createRaci({ r2MemberId: 'qa', cMemberIds: ['head'] });
+
+    expect(applyRaciDefaults(raci, { cMemberIds: ['qa'], iMemberIds: ['head', 'm1'] })).toBe(raci);
+  });
+
+  it('should prefer C if a member is a default for both', () => {
+    const result = applyRaciDefaults(createRaci(), { cMemberIds: ['x'], iMemberIds: ['x'] });
+
+    expect(result.cMemberIds).toEqual(['x']);
+    expect(result.iMemberIds).toEqual([]);
+  });
+
+  it('should return the same RACI without defaults', () => {
+    const raci = createRaci();
+
+    expect(applyRaciDefaults(raci, undefined)).toBe(raci);
+  });
+});
@@ -0,0 +1,40 @@
+/**
+ * Workspace-wide RACI defaults (e.g. quality officer always C, department head always I).
+ */
+
+import { RaciDefaults, TopicRaci } from '../../core/models';
+
+/**
+ * Returns all member IDs that already have a role in the topic.
+ */
+function getAssignedMemberIds(raci: TopicRaci): Set<string> {
+  return new Set(
+    [raci.r1MemberId, raci.r2MemberId, raci.r3MemberId, ...raci.cMemberIds, ...raci.iMemberIds]
+      .filter((id): id is string => !!id)
+  );
+}
+
+/**
+ * Adds the default C and I members to a topic's RACI.
+ * Members that already have a role in the topic are skipped, so explicit assignments win.
+ *
+ * @returns The extended RACI, or the same object if nothing was added
+ */
+export function applyRaciDefaults(raci: TopicRaci, defaults: RaciDefaults | undefined): TopicRaci {
+  if (!defaults) {
+    return raci;
+  }
+  const assigned = getAssignedMemberIds(raci);
+  const addC = defaults.cMemberIds.filter(id => !assigned.has(id));
+  addC.forEach(id => assigned.add(id));
+  const addI = defaults.iMemberIds.filter(id => !assigned.has(id));
+
+  if (addC.length === 0 && addI.length === 0) {
+    return raci;
+  }
+  return {
+    ...raci,
+    cMemberIds: [...raci.cMemberIds, ...addC],
+    iMemberIds: [...raci.iMemberIds, ...addI]
+  };
+}
@@ -0,0 +1,174 @@
+import { describe, it, expect } from 'vitest';
+import { describeResponsibleResolution, getRotationMemberId, resolveResponsibleNow } from './responsible.utils';
+import { TeamMember, Topic } from '../../core/models';
+
+const createMember = (id: string, overrides?: Partial<TeamMember>): TeamMember => ({
+  id,
+  displayName: `Member ${id}`,
+  active: true,
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  ...overrides
+});
+
+const createTopic = (r1: string, r2?: string, r3?: string): Topic => ({
+  id: 't1',
+  header: 'Topic',
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: r1, r2MemberId: r2, r3MemberId: r3, cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z'
+});
+
+const now = new Date(2024, 5, 5, 12);
+const vacation = { from: '2024-06-01', to: '2024-06-14', type: 'vacation' as const };
+
+describe('resolveResponsibleNow', () => {
+  it('should choose R1 if available', () => {
+    const result = resolveResponsibleNow(createTopic('m1', 'm2'), [createMember('m1'), createMember('m2')], now);
+
+    expect(result.member?.id).toBe('m1');
+    expect(result.role).toBe('R1');
+    expect(result.skipped).toEqual([]);
+  });
+
+  it('should fall back to R2 if R1 is absent', () => {
+    const members = [createMember('m1', { absences: [vacation] }), createMember('m2')];
+
+    const result = resolveResponsibleNow(createTopic('m1', 'm2'), members, now);
+
+    expect(result.member?.id).toBe('m2');
+    expect(result.role).toBe('R2');
+    expect(result.skipped).toHaveLength(1);
+    expect(result.skipped[0].reason).toBe('absent');
+  });
+
+  it('should skip inactive and unknown members', () => {
+    const members = [createMember('m2', { active: false }), createMember('m3')];
+
+    const result = resolveResponsibleNow(createTopic('gone', 'm2', 'm3'), members, now);
+
+    expect(result.member?.id).toBe('m3');
+    expect(result.role).toBe('R3');
+    expect(result.skipped.map(s => s.reason)).toEqual(['unknown', 'inactive']);
+  });
+
+  it('should return no member if nobody is available', () => {
+    const result = resolveResponsibleNow(createTopic('m1'), [createMember('m1', { absences: [vacation] })], now);
+
+    expect(result.member).toBeUndefined();
+    expect(result.role).toBeUndefined();
+    expect(result.skipped).toHaveLength(1);
+  });
+
+  it('should continue with escalation contacts after R3', () => {
+    const topic = { ...createTopic('m1', 'm2'), raci: { ...createTopic('m1', 'm2').raci, escalationMemberIds: ['m3', 'm4'] } };
+    const members = [
+      createMember('m1', { active: false }),
+      createMember('m2', { absences: [vacation] }),
+      createMember('m3', { absences: [vacation] }),
+      createMember('m4')
+    ];
+
+    const result = resolveResponsibleNow(topic, members, now);
+
+    expect(result.member?.id).toBe('m4');
+    expect(result.role).toBe('E2');
+    expect(result.skipped.map(s => s.role)).toEqual(['R1', 'R2', 'E1']);
+  });
+
+  it('should choose the delegate of an active delegation', () => {
+    const topic = { ...createTopic('m1', 'm2'), delegation: { memberId: 'm3', from: '2024-06-01', to: '2024-06-30', reason: 'Projekt' } };
+
+    const result = resolveResponsibleNow(topic, [createMember('m1'), createMember('m2'), createMember('m3')], now);
+
+    expect(result.member?.id).toBe('m3');
+    expect(result.role).toBe('D');
+    expect(result.delegation).toBe(topic.delegation);
+    expect(describeResponsibleResolution(result)).toBe('delegiert bis 30.06.2024 (Projekt)');
+  });
+
+  it('should ignore delegations outside their date range', () => {
+    const topic = { ...createTopic('m1'), delegation: { memberId: 'm3', from: '2024-07-01', to: '2024-07-31' } };
+
+    const result = resolveResponsibleNow(topic, [createMember('m1'), createMember('m3')], now);
+
+    expect(result.role).toBe('R1');
+    expect(result.delegation).toBeUndefined();
+  });
+
+  it('should fall back to R1 if the delegate is absent', () => {
+    const topic = { ...createTopic('m1'), delegation: { memberId: 'm3', from: '2024-06-01', to: '2024-06-30' } };
+
+    const result = resolveResponsibleNow(topic, [createMember('m1'), createMember('m3', { absences: [vacation] })], now);
+
+    expect(result.role).toBe('R1');
+    expect(result.skipped.map(s => s.role)).toEqual(['D']);
+  });
+
+  it('should choose R1 again after the absence', () => {
+    const members = [createMember('m1', { absences: [vacation] }), createMember('m2')];
+
+    const result = resolveResponsibleNow(createTopic('m1', 'm2'), members, new Date(2024, 5, 15));
+
+    expect(result.role).toBe('R1');
+  });
+});
+
+describe('describeResponsibleResolution', () => {
+  it('should describe skipped roles', () => {
+    const members = [createMember('m1', { absences: [vacation] }), createMember('m2', { active: false }), createMember('m3')];
+
+    const result = resolveResponsibleNow(createTopic('m1', 'm2', 'm3'), members, now);
+
+    expect(describeResponsibleResolution(result)).toBe(
+      'R1 Member m1: abwesend (Urlaub bis 14.06.2024), R2 Member m2: inaktiv'
+    );
+  });
+
+  it('should return an empty string if R1 was chosen', () => {
+    const result = resolveResponsibleNow(createTopic('m1'), [createMember('m1')], now);
+
+    expect(describeResponsibleResolution(result)).toBe('');
+  });
+});
+
+describe('getRotationMemberId', () => {
+  it('should switch weekly from the start date', () => {
+    const rotation = { memberIds: ['m1', 'm2', 'm3'], period: 'weekly' as const, startDate: '2024-06-03' };
+
+    expect(getRotationMemberId(rotation, new Date(2024, 5, 3, 8))).toBe('m1');
+    expect(getRotationMemberId(rotation, new Date(2024, 5, 9, 23))).toBe('m1');
+    expect(getRotationMemberId(rotation, new Date(2024, 5, 10))).toBe('m2');
+    expect(getRotationMemberId(rotation, new Date(2024, 5, 17))).toBe('m3');
+    expect(getRotationMemberId(rotation, new Date(2024, 5, 24))).toBe('m1');
+  });
+
+  it('should not be affected by daylight saving time', () => {
+    const rotation = { memberIds: ['m1', 'm2'], period: 'weekly' as const, startDate: '2024-03-25' };
+
+    expect(getRotationMemberId(rotation, new Date(2024, 2, 31, 0, 30))).toBe('m1');
+    expect(getRotationMemberId(rotation, new Date(2024, 3, 1, 0, 30))).toBe('m2');
+  });
+
+  it('should switch monthly on the day of month of the start date', () => {
+    const rotation = { memberIds: ['m1', 'm2'], period: 'monthly' as const, startDate: '2024-01-15' };
+
+    expect(getRotationMemberId(rotation, new Date(2024, 1, 14))).toBe('m1');
+    expect(getRotationMemberId(rotation, new Date(2024, 1, 15))).toBe('m2');
+    expect(getRotationMemberId(rotation, new Date(2024, 2, 15))).toBe('m1');
+  });
+
+  it('should return undefined before the start or without members', () => {
+    expect(getRotationMemberId({ memberIds: ['m1'], period: 'weekly', startDate: '2024-06-03' }, new Date(2024, 5, 2))).toBeUndefined();
+    expect(getRotationMemberId({ memberIds: [], period: 'weekly', startDate: '2024-06-03' }, new Date(2024, 5, 5))).toBeUndefined();
+    expect(getRotationMemberId(undefined)).toBeUndefined();
+  });
+
+  it('should take precedence over R1 in resolveResponsibleNow', () => {
+    const topic = { ...createTopic('m1'), rotation: { memberIds: ['m2', 'm3'], period: 'weekly' as const, startDate: '2024-06-03' } };
+
+    const result = resolveResponsibleNow(topic, [createMember('m1'), createMember('m2'), createMember('m3')], now);
+
+    expect(result.member?.id).toBe('m2');
+    expect(result.role).toBe('RO');
+  });
+});
@@ -0,0 +1,196 @@
+/**
+ * Resolution of the effective responsible person of a topic.
+ *
+ * An active delegation takes precedence, followed by the current member of a
+ * rotation. Otherwise the responsible roles are tried in the order R1 → R2 → R3,
+ * followed by the escalation contacts (E1, E2, ...).
+ * A member is skipped if they no longer exist, are inactive or are absent at the
+ * given point in time.
+ */
+
+import { TeamMember, Topic, TopicDelegation, TopicRotation } from '../../core/models';
+import { getCurrentAbsence, ABSENCE_TYPE_LABELS } from './absence.utils';
+import { formatValidityDate, getValidityStatus, parseValidityDate } from './validity.utils';
+
+/** Responsible role; 'D' is the delegate of an active delegation, 'RO' the current rotation member */
+export type ResponsibleRole = 'D' | 'RO' | 'R1' | 'R2' | 'R3' | `E${number}`;
+
+/**
+ * Returns a display label for a responsible role.
+ */
+export function formatResponsibleRole(role: ResponsibleRole): string {
+  switch (role) {
+    case 'D': return 'Delegation';
+    case 'RO': return 'Rotation';
+    default: return role;
+  }
+}
+
+/**
+ * Why a responsible role was skipped.
+ */
+export type SkipReason = 'unknown' | 'inactive' | 'absent';
+
+export interface SkippedResponsible {
+  role: ResponsibleRole;
+  memberId: string;
+  member?: TeamMember;
+  reason: SkipReason;
+  /** Description of the reason (German, for display) */
+  detail: string;
+}
+
+export interface ResponsibleResolution {
+  /** The member to contact, or undefined if nobody is available */
+  member?: TeamMember;
+  /** Role of the member to contact */
+  role?: ResponsibleRole;
+  /** Roles skipped before the member was found, in order */
+  skipped: SkippedResponsible[];
+  /** The delegation in effect, if any */
+  delegation?: TopicDelegation;
+}
+
+/**
+ * Checks whether a delegation has valid dates (to not before from) and a delegate.
+ */
+export function isValidDelegation(delegation: TopicDelegation): boolean {
+  const from = parseValidityDate(delegation.from);
+  const to = parseValidityDate(delegation.to);
+  return !!delegation.memberId && !!from && !!to && to >= from;
+}
+
+/**
+ * Checks whether a delegation is in effect at the given point in time.
+ */
+export function isDelegationActive(delegation: TopicDelegation | undefined, now: Date = new Date()): boolean {
+  return !!delegation && isValidDelegation(delegation) &&
+    getValidityStatus({ alwaysValid: false, validFrom: delegation.from, validTo: delegation.to }, now) === 'valid';
+}
+
+/**
+ * Checks whether a delegation has ended before the given point in time.
+ */
+export function isDelegationExpired(delegation: TopicDelegation, now: Date = new Date()): boolean {
+  return isValidDelegation(delegation) &&
+    getValidityStatus({ alwaysValid: false, validFrom: delegation.from, validTo: delegation.to }, now) === 'expired';
+}
+
+/**
+ * Checks whether a rotation has at least one member and a valid start date.
+ */
+export function isValidRotation(rotation: TopicRotation): boolean {
+  return rotation.memberIds.length > 0 &&
+    (rotation.period === 'weekly' || rotation.period === 'monthly') &&
+    parseValidityDate(rotation.startDate) !== null;
+}
+
+/**
+ * Returns the member in charge of a rotation at the given point in time.
+ * Weekly rotations switch every 7 days, monthly rotations on the day of month
+ * of the start date.
+ *
+ * @param rotation The rotation
+ * @param now Point in time to evaluate (default: current time)
+ * @returns The member ID, or undefined before the start or for invalid rotations
+ */
+export function getRotationMemberId(rotation: TopicRotation | undefined, now: Date = new Date()): string | undefined {
+  if (!rotation || !isValidRotation(rotation)) {
+    return undefined;
+  }
+  const start = parseValidityDate(rotation.startDate)!;
+  if (now < start) {
+    return undefined;
+  }
+
+  let periods: number;
+  if (rotation.period === 'weekly') {
+    // Count calendar days in UTC to be independent of daylight saving time
+    const days = Math.floor(
+      (Date.UTC(now.getFullYear(), now.getMonth(), now.getDate()) -
+        Date.UTC(start.getFullYear(), start.getMonth(), start.getDate())) / 86_400_000
+    );
+    periods = Math.floor(days / 7);
+  } else {
+    periods = (now.getFullYear() - start.getFullYear()) * 12 + (now.getMonth() - start.getMonth());
+    if (now.getDate() < start.getDate()) {
+      periods--;
+    }
+  }
+  return rotation.memberIds[periods % rotation.memberIds.length];
+}
+
+/**
+ * Resolves who is responsible for a topic at the given point in time.
+ *
+ * @param topic The topic
+ * @param members All team members
+ * @param now Point in time to evaluate (default: current time)
+ * @returns The member to contact and the skipped roles
+ */
+export function resolveResponsibleNow(
+  topic: Topic,
+  members: TeamMember[],
+  now: Date = new Date()
+): ResponsibleResolution {
+  const delegation = isDelegationActive(topic.delegation, now) ? topic.delegation : undefined;
+  const candidates: Array<[ResponsibleRole, string | undefined]> = [
+    ['D', delegation?.memberId],
+    ['RO', getRotationMemberId(topic.rotation, now)],
+    ['R1', topic.raci.r1MemberId],
+    ['R2', topic.raci.r2MemberId],
+    ['R3', topic.raci.r3MemberId],
+    ...(topic.raci.escalationMemberIds || []).map(
+      (id, index): [ResponsibleRole, string] => [`E${index + 1}`, id]
+    )
+  ];
+  const skipped: SkippedResponsible[] = [];
+
+  for (const [role, memberId] of candidates) {
+    if (!memberId) {
+      continue;
+    }
+    const member = members.find(m => m.id === memberId);
+    if (!member) {
+      skipped.push({ role, memberId, reason: 'unknown', detail: 'unbekanntes Mitglied' });
+      continue;
+    }
+    if (!member.active) {
+      skipped.push({ role, memberId, member, reason: 'inactive', detail: 'inaktiv' });
+      continue;
+    }
+    const absence = getCurrentAbsence(member, now);
+    if (absence) {
+      skipped.push({
+        role,
+        memberId,
+        member,
+        reason: 'absent',
+        detail: `abwesend (${ABSENCE_TYPE_LABELS[absence.type]} bis ${formatValidityDate(absence.to)})`
+      });
+      continue;
+    }
+    return { member, role, skipped, delegation };
+  }
+
+  return { skipped, delegation };
+}
+
+/**
+ * Describes why the resolved member was chosen (German, for display),
+ * e.g. "R1 Max Muster: abwesend (Urlaub bis 14.06.2024)".
+ *
+ * @param resolution Result of resolveResponsibleNow
+ * @returns Description, or an empty string if R1 was chosen directly
+ */
+export function describeResponsibleResolution(resolution: ResponsibleResolution): string {
+  const parts: string[] = [];
+  if (resolution.delegation) {
+    const reason = resolution.delegation.reason ? ` (${resolution.delegation.reason})` : '';
+    parts.push(`delegiert bis ${formatValidityDate(resolution.delegation.to)}${reason}`);
+  }
+  parts.push(...resolution.skipped.map(s =>
+    `${s.role}${s.member ? ' ' + s.member.displayName : ''}: ${s.detail}`
+  ));
+  return parts.join(', ');
+}
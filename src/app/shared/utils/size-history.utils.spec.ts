@@ -0,0 +1,47 @@
+import { describe, it, expect } from 'vitest';
+import { recordSizeChange } from './size-history.utils';
+import { Topic } from '../../core/models';
+
+const createTopic = (overrides?: Partial<Topic>): Topic => ({
+  id: 't1',
+  header: 'Topic',
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  ...overrides
+});
+
+describe('recordSizeChange', () => {
+  const changedAt = '2024-05-01T08:30:00.000Z';
+
+  it('should append an entry when the size changes', () => {
+    const result = recordSizeChange(createTopic({ size: 'M' }), createTopic({ size: 'L' }), changedAt, 'm2');
+
+    expect(result.sizeHistory).toEqual([{ from: 'M', to: 'L', changedAt, changedBy: 'm2' }]);
+  });
+
+  it('should record setting and clearing the size', () => {
+    const set = recordSizeChange(createTopic(), createTopic({ size: 'S' }), changedAt);
+    const cleared = recordSizeChange(set, { ...set, size: undefined }, changedAt);
+
+    expect(cleared.sizeHistory).toEqual([
+      { from: undefined, to: 'S', changedAt },
+      { from: 'S', to: undefined, changedAt }
+    ]);
+  });
+
+  it('should keep the existing history', () => {
+    const history = [{ from: 'S' as const, to: 'M' as const, changedAt: '2024-01-01T00:00:00.000Z' }];
+
+    const result = recordSizeChange(createTopic({ size: 'M', sizeHistory: history }), createTopic({ size: 'XL' }), changedAt);
+
+    expect(result.sizeHistory).toHaveLength(2);
+    expect(result.sizeHistory![0]).toEqual(history[0]);
+  });
+
+  it('should not change the topic if the size is unchanged', () => {
+    const after = createTopic({ size: 'M', header: 'Renamed' });
+
+    expect(recordSizeChange(createTopic({ size: 'M' }), after, changedAt)).toBe(after);
+  });
+});
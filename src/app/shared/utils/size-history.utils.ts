@@ -0,0 +1,27 @@
+/**
+ * History of T-shirt size (effort estimate) changes of a topic.
+ */
+
+import { Topic } from '../../core/models';
+
+/**
+ * Appends a size change entry to the updated topic if its size differs from the previous version.
+ *
+ * @param before Topic before the update
+ * @param after Topic after the update
+ * @param changedAt Timestamp of the update
+ * @param changedBy Member ID of the editor
+ * @returns The updated topic, with an extended size history if the size changed
+ */
+export function recordSizeChange(before: Topic, after: Topic, changedAt: string, changedBy?: string): Topic {
+  if ((before.size ?? undefined) === (after.size ?? undefined)) {
+    return after;
+  }
+  return {
+    ...after,
+    sizeHistory: [
+      ...(after.sizeHistory ?? before.sizeHistory ?? []),
+      { from: before.size ?? undefined, to: after.size ?? undefined, changedAt, ...(changedBy ? { changedBy } : {}) }
+    ]
+  };
+}
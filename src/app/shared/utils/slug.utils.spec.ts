@@ -0,0 +1,144 @@
+import { describe, it, expect } from 'vitest';
+import {
+  assignMissingSlugs,
+  collectUsedSlugs,
+  createUniqueSlug,
+  findTopicBySlug,
+  slugify,
+  updateTopicSlug
+} from './slug.utils';
+import { Datastore, Topic } from '../../core/models';
+
+const createTopic = (id: string, header: string, overrides?: Partial<Topic>): Topic => ({
+  id,
+  header,
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  ...overrides
+});
+
+const createDatastore = (topics: Topic[]): Datastore => ({
+  schemaVersion: 1,
+  generatedAt: '2024-01-01T00:00:00.000Z',
+  revisionId: 1,
+  members: [],
+  topics
+});
+
+describe('slugify', () => {
+  it('should create lower case slugs with hyphens', () => {
+    expect(slugify('  Server Wartung / Backup!  ')).toBe('server-wartung-backup');
+  });
+
+  it('should transliterate umlauts and strip accents', () => {
+    expect(slugify('Größe prüfen für Café')).toBe('groesse-pruefen-fuer-cafe');
+  });
+});
+
+describe('createUniqueSlug', () => {
+  it('should add a numeric suffix for used slugs', () => {
+    expect(createUniqueSlug('Backup', new Set(['backup', 'backup-2']))).toBe('backup-3');
+  });
+
+  it('should fall back to a default slug for empty headers', () => {
+    expect(createUniqueSlug('!!!', new Set())).toBe('thema');
+  });
+});
+
+describe('collectUsedSlugs', () => {
+  it('should include aliases and ignore the given topic', () => {
+    const topics = [
+      createTopic('t1', 'A', { slug: 'a', slugAliases: ['old-a'] }),
+      createTopic('t2', 'B', { slug: 'b' })
+    ];
+
+    expect([...collectUsedSlugs(topics, 't2')]).toEqual(['a', 'old-a']);
+  });
+});
+
+describe('updateTopicSlug', () => {
+  it('should keep the slug if it matches the header', () => {
+    const topic = createTopic('t1', 'Backup', { slug: 'backup' });
+
+    expect(updateTopicSlug(topic, [topic])).toBe(topic);
+  });
+
+  it('should keep a counter slug while the plain slug is taken', () => {
+    const other = createTopic('t2', 'Backup', { slug: 'backup' });
+    const topic = createTopic('t1', 'Backup', { slug: 'backup-2' });
+
+    expect(updateTopicSlug(topic, [other, topic])).toBe(topic);
+  });
+
+  it('should not treat a numeric header suffix as a counter after a rename', () => {
+    const topic = createTopic('t1', 'Backup', { slug: 'backup-2024' });
+
+    const result = updateTopicSlug(topic, [topic]);
+
+    expect(result.slug).toBe('backup');
+    expect(result.slugAliases).toEqual(['backup-2024']);
+  });
+
+  it('should keep the previous slug as alias after a rename', () => {
+    const topic = createTopic('t1', 'Datensicherung', { slug: 'backup' });
+
+    const result = updateTopicSlug(topic, [topic]);
+
+    expect(result.slug).toBe('datensicherung');
+    expect(result.slugAliases).toEqual(['backup']);
+  });
+
+  it('should reuse an own alias when renamed back', () => {
+    const topic = createTopic('t1', 'Backup', { slug: 'datensicherung', slugAliases: ['backup'] });
+
+    const result = updateTopicSlug(topic, [topic]);
+
+    expect(result.slug).toBe('backup');
+    expect(result.slugAliases).toEqual(['datensicherung']);
+  });
+
+  it('should not take slugs or aliases of other topics', () => {
+    const other = createTopic('t2', 'Other', { slug: 'other', slugAliases: ['backup'] });
+    const topic = createTopic('t1', 'Backup', { slug: 'x' });
+
+    expect(updateTopicSlug(topic, [other, topic]).slug).toBe('backup-2');
+  });
+});
+
+describe('assignMissingSlugs', () => {
+  it('should assign unique slugs in list order', () => {
+    const result = assignMissingSlugs(createDatastore([
+      createTopic('t1', 'Backup'),
+      createTopic('t2', 'Backup'),
+      createTopic('t3', 'Other', { slug: 'other' })
+    ]));
+
+    expect(result.topics.map(t => t.slug)).toEqual(['backup', 'backup-2', 'other']);
+  });
+
+  it('should return the same datastore if all topics have slugs', () => {
+    const datastore = createDatastore([createTopic('t1', 'A', { slug: 'a' })]);
+
+    expect(assignMissingSlugs(datastore)).toBe(datastore);
+  });
+});
+
+describe('findTopicBySlug', () => {
+  const topics = [
+    createTopic('t1', 'Datensicherung', { slug: 'datensicherung', slugAliases: ['backup'] }),
+    createTopic('t2', 'Backup neu', { slug: 'backup-neu' })
+  ];
+
+  it('should find topics by current slug', () => {
+    expect(findTopicBySlug(topics, 'Backup-Neu')?.id).toBe('t2');
+  });
+
+  it('should resolve previous slugs', () => {
+    expect(findTopicBySlug(topics, 'backup')?.id).toBe('t1');
+  });
+
+  it('should return undefined for unknown slugs', () => {
+    expect(findTopicBySlug(topics, 'missing')).toBeUndefined();
+  });
+});
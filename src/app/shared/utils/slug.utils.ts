@@ -0,0 +1,101 @@
+/**
+ * URL slugs for topics, derived from the header.
+ * When a header changes, the previous slug is kept as an alias so shared links keep resolving.
+ */
+
+import { Datastore, Topic } from '../../core/models';
+
+const DEFAULT_SLUG = 'thema';
+
+const GERMAN_TRANSLITERATIONS: Record<string, string> = { ä: 'ae', ö: 'oe', ü: 'ue', ß: 'ss' };
+
+/**
+ * Converts a text to a URL slug (lower case, ASCII letters, digits and hyphens).
+ */
+export function slugify(text: string): string {
+  return text
+    .toLowerCase()
+    .replace(/[äöüß]/g, char => GERMAN_TRANSLITERATIONS[char])
+    .normalize('NFD')
+    .replace(/[\u0300-\u036f]/g, '')
+    .replace(/[^a-z0-9]+/g, '-')
+    .replace(/^-+|-+$/g, '');
+}
+
+/**
+ * Returns the first slug for the text that is not in use, adding "-2", "-3", ... if needed.
+ */
+export function createUniqueSlug(text: string, usedSlugs: Set<string>): string {
+  const base = slugify(text) || DEFAULT_SLUG;
+  let slug = base;
+  for (let suffix = 2; usedSlugs.has(slug); suffix++) {
+    slug = `${base}-${suffix}`;
+  }
+  return slug;
+}
+
+/**
+ * Collects all slugs and aliases in use, optionally ignoring one topic.
+ */
+export function collectUsedSlugs(topics: Topic[], ignoreTopicId?: string): Set<string> {
+  const used = new Set<string>();
+  for (const topic of topics) {
+    if (topic.id === ignoreTopicId) {
+      continue;
+    }
+    if (topic.slug) {
+      used.add(topic.slug);
+    }
+    topic.slugAliases?.forEach(alias => used.add(alias));
+  }
+  return used;
+}
+
+/**
+ * Updates the slug of a topic after its header changed.
+ * The previous slug becomes an alias; a former alias matching the new header is reused.
+ * The topic is returned unchanged if its slug still matches the header, i.e. it equals
+ * the slugified header or is a counter variant (`backup-2`) while `backup` is taken.
+ */
+export function updateTopicSlug(topic: Topic, topics: Topic[]): Topic {
+  const base = slugify(topic.header) || DEFAULT_SLUG;
+  const usedByOthers = collectUsedSlugs(topics, topic.id);
+  if (topic.slug && (topic.slug === base || (usedByOthers.has(base) && new RegExp(`^${base}-\\d+$`).test(topic.slug)))) {
+    return topic;
+  }
+  const ownAliases = topic.slugAliases ?? [];
+  const reusable = ownAliases.find(alias => alias === base);
+  const slug = reusable ?? createUniqueSlug(topic.header, usedByOthers);
+  const aliases = [...ownAliases.filter(alias => alias !== slug), ...(topic.slug ? [topic.slug] : [])];
+  return { ...topic, slug, ...(aliases.length > 0 ? { slugAliases: aliases } : {}) };
+}
+
+/**
+ * Assigns slugs to topics without one, in list order.
+ *
+ * @returns The datastore with slugs, or the same datastore if nothing changed
+ */
+export function assignMissingSlugs(datastore: Datastore): Datastore {
+  if (datastore.topics.every(topic => topic.slug)) {
+    return datastore;
+  }
+  const used = collectUsedSlugs(datastore.topics);
+  const topics = datastore.topics.map(topic => {
+    if (topic.slug) {
+      return topic;
+    }
+    const slug = createUniqueSlug(topic.header, used);
+    used.add(slug);
+    return { ...topic, slug };
+  });
+  return { ...datastore, topics };
+}
+
+/**
+ * Finds a topic by its current slug or a previous one.
+ */
+export function findTopicBySlug(topics: Topic[], slug: string): Topic | undefined {
+  const normalized = slug.trim().toLowerCase();
+  return topics.find(topic => topic.slug === normalized)
+    ?? topics.find(topic => topic.slugAliases?.includes(normalized));
+}
@@ -0,0 +1,33 @@
+import { describe, it, expect } from 'vitest';
+import { compareLocale, sortLocale } from './sort.utils';
+
+describe('compareLocale', () => {
+  it('should sort umlauts with their base letter', () => {
+    expect(compareLocale('Ärzte', 'Zebra')).toBeLessThan(0);
+    expect(compareLocale('Öffentlichkeit', 'Post')).toBeLessThan(0);
+    expect(compareLocale('Übergabe', 'Verwaltung')).toBeLessThan(0);
+  });
+
+  it('should ignore case', () => {
+    expect(compareLocale('anna', 'Anna')).toBe(0);
+    expect(compareLocale('bernd', 'Clara')).toBeLessThan(0);
+  });
+
+  it('should sort numbers naturally', () => {
+    expect(compareLocale('Team 2', 'Team 10')).toBeLessThan(0);
+  });
+});
+
+describe('sortLocale', () => {
+  it('should return a sorted copy', () => {
+    const input = ['Zebra', 'Ärzte', 'apfel', 'Müller', 'Meier'];
+    const sorted = sortLocale(input);
+
+    expect(sorted).toEqual(['apfel', 'Ärzte', 'Meier', 'Müller', 'Zebra']);
+    expect(input[0]).toBe('Zebra');
+  });
+
+  it('should accept any iterable', () => {
+    expect(sortLocale(new Set(['b', 'a']))).toEqual(['a', 'b']);
+  });
+});
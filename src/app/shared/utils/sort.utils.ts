@@ -0,0 +1,31 @@
+/**
+ * Shared locale-aware sorting utilities.
+ *
+ * Uses German collation so that umlauts sort with their base letter
+ * ("Ärzte" before "Zebra"), case is ignored and numbers sort naturally
+ * ("Team 2" before "Team 10").
+ */
+
+const collator = new Intl.Collator('de', { sensitivity: 'base', numeric: true });
+
+/**
+ * Compares two strings using German collation.
+ * Usable directly as an Array.prototype.sort comparator.
+ *
+ * @param a First string
+ * @param b Second string
+ * @returns Negative, zero or positive number
+ */
+export function compareLocale(a: string, b: string): number {
+  return collator.compare(a ?? '', b ?? '');
+}
+
+/**
+ * Returns a new array with the strings sorted using German collation.
+ *
+ * @param values Strings to sort
+ * @returns Sorted copy of the input
+ */
+export function sortLocale(values: Iterable<string>): string[] {
+  return Array.from(values).sort(compareLocale);
+}
@@ -0,0 +1,30 @@
+import { describe, it, expect } from 'vitest';
+import { buildStopWordSet, parseStopWords } from './stop-words.utils';
+
+describe('parseStopWords', () => {
+  it('should split on whitespace, commas and semicolons', () => {
+    expect(parseStopWords(' Abt, IT;  ref\nabt ')).toEqual(['abt', 'it', 'ref']);
+  });
+
+  it('should return an empty list for empty text', () => {
+    expect(parseStopWords('  ')).toEqual([]);
+  });
+});
+
+describe('buildStopWordSet', () => {
+  it('should be empty without configuration', () => {
+    expect(buildStopWordSet(undefined).size).toBe(0);
+  });
+
+  it('should include the German list only if enabled', () => {
+    expect(buildStopWordSet({ useGermanList: true, custom: [] }).has('und')).toBe(true);
+    expect(buildStopWordSet({ useGermanList: false, custom: [] }).has('und')).toBe(false);
+  });
+
+  it('should include custom words with and without diacritics', () => {
+    const stopWords = buildStopWordSet({ useGermanList: false, custom: ['Bürgeramt'] });
+
+    expect(stopWords.has('bürgeramt')).toBe(true);
+    expect(stopWords.has('burgeramt')).toBe(true);
+  });
+});
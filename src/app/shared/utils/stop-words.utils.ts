@@ -0,0 +1,45 @@
+/**
+ * Stop words: terms that are ignored by the search index and keyword suggestions.
+ */
+
+import { SearchStopWords } from '../../core/models';
+
+/** Common German words; always ignored for keyword suggestions, optionally by the search */
+export const GERMAN_STOP_WORDS: ReadonlySet<string> = new Set([
+  'aber', 'alle', 'allem', 'allen', 'aller', 'alles', 'als', 'also', 'am', 'an', 'auch', 'auf', 'aus', 'bei',
+  'beim', 'bin', 'bis', 'bitte', 'da', 'damit', 'dann', 'das', 'dass', 'dem', 'den', 'denn', 'der', 'des',
+  'die', 'dies', 'diese', 'diesem', 'diesen', 'dieser', 'dieses', 'doch', 'dort', 'durch', 'ein', 'eine',
+  'einem', 'einen', 'einer', 'eines', 'er', 'es', 'etc', 'etwa', 'für', 'gibt', 'hat', 'haben', 'hier',
+  'ihr', 'ihre', 'im', 'in', 'ins', 'ist', 'ja', 'je', 'jede', 'jeder', 'jedes', 'kann', 'kein', 'keine',
+  'man', 'mehr', 'mit', 'muss', 'nach', 'nicht', 'noch', 'nur', 'ob', 'oder', 'ohne', 'sich', 'sie',
+  'sind', 'so', 'soll', 'sowie', 'über', 'um', 'und', 'uns', 'unter', 'vom', 'von', 'vor', 'war', 'was',
+  'wenn', 'werden', 'wie', 'wird', 'wir', 'wo', 'zu', 'zum', 'zur', 'zwischen'
+]);
+
+/**
+ * Removes diacritics, matching the normalization of the search encoder (ä → a).
+ */
+function stripDiacritics(word: string): string {
+  return word.normalize('NFD').replace(/[\u0300-\u036f]/g, '');
+}
+
+/**
+ * Parses stop words from free text (separated by whitespace, commas or semicolons).
+ * Returns unique lower-case words in input order.
+ */
+export function parseStopWords(text: string): string[] {
+  return [...new Set(text.split(/[\s,;]+/).map(word => word.trim().toLowerCase()).filter(word => word !== ''))];
+}
+
+/**
+ * Builds the set of stop words from the workspace configuration.
+ * Each word is included as written and without diacritics, so it matches before
+ * and after the normalization of the search encoder.
+ */
+export function buildStopWordSet(config: SearchStopWords | undefined): Set<string> {
+  const words = [
+    ...(config?.useGermanList ? GERMAN_STOP_WORDS : []),
+    ...(config?.custom ?? []).map(word => word.toLowerCase())
+  ];
+  return new Set(words.flatMap(word => [word, stripDiacritics(word)]));
+}
@@ -0,0 +1,108 @@
+import { describe, it, expect } from 'vitest';
+import { computeTagAssignment, countTagUsage, findTagOrphans } from './tag.utils';
+import { Tag, Topic } from '../../core/models';
+
+const createTopic = (id: string, tags?: string[]): Topic => ({
+  id,
+  header: `Topic ${id}`,
+  tags,
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z'
+});
+
+const createTag = (name: string): Tag => ({
+  id: `tag-${name}`,
+  name,
+  createdAt: '2024-01-01T00:00:00.000Z',
+  modifiedAt: '2024-01-01T00:00:00.000Z',
+  createdBy: 'm1'
+});
+
+describe('computeTagAssignment', () => {
+  const topics = [
+    createTopic('t1', ['HR']),
+    createTopic('t2', ['IT']),
+    createTopic('t3'),
+    createTopic('t4', ['HR', 'IT'])
+  ];
+
+  it('should add the tag to selected topics without it', () => {
+    const result = computeTagAssignment(topics, 'HR', ['t1', 't2', 't3', 't4']);
+
+    expect(result.added).toBe(2);
+    expect(result.removed).toBe(0);
+    expect(result.updates).toEqual([
+      { topicId: 't2', changes: { tags: ['IT', 'HR'] } },
+      { topicId: 't3', changes: { tags: ['HR'] } }
+    ]);
+  });
+
+  it('should remove the tag from unselected topics', () => {
+    const result = computeTagAssignment(topics, 'HR', ['t1']);
+
+    expect(result.added).toBe(0);
+    expect(result.removed).toBe(1);
+    expect(result.updates).toEqual([{ topicId: 't4', changes: { tags: ['IT'] } }]);
+  });
+
+  it('should return no updates if the selection matches', () => {
+    const result = computeTagAssignment(topics, 'HR', ['t1', 't4']);
+
+    expect(result.updates).toEqual([]);
+  });
+
+  it('should add and remove in one pass', () => {
+    const result = computeTagAssignment(topics, 'IT', ['t1']);
+
+    expect(result.added).toBe(1);
+    expect(result.removed).toBe(2);
+    expect(result.updates.map(u => u.topicId)).toEqual(['t1', 't2', 't4']);
+  });
+});
+
+describe('countTagUsage', () => {
+  it('should count topics per tag name', () => {
+    const counts = countTagUsage([createTopic('t1', ['HR', 'IT']), createTopic('t2', ['HR']), createTopic('t3')]);
+
+    expect(counts.get('HR')).toBe(2);
+    expect(counts.get('IT')).toBe(1);
+    expect(counts.has('Finance')).toBe(false);
+  });
+
+  it('should count a topic only once per tag', () => {
+    const counts = countTagUsage([createTopic('t1', ['HR', 'HR'])]);
+
+    expect(counts.get('HR')).toBe(1);
+  });
+});
+
+describe('findTagOrphans', () => {
+  it('should find managed tags not used by any topic', () => {
+    const result = findTagOrphans(
+      [createTag('HR'), createTag('IT'), createTag('Finance')],
+      [createTopic('t1', ['HR']), createTopic('t2', ['HR', 'IT'])]
+    );
+
+    expect(result.unusedTags.map(t => t.name)).toEqual(['Finance']);
+  });
+
+  it('should find topic tag names without a managed tag', () => {
+    const result = findTagOrphans(
+      [createTag('HR')],
+      [createTopic('t1', ['HR', 'Legacy']), createTopic('t2', ['Legacy', 'Alt']), createTopic('t3')]
+    );
+
+    expect(result.unknownTagNames).toEqual([
+      { name: 'Alt', count: 1 },
+      { name: 'Legacy', count: 2 }
+    ]);
+  });
+
+  it('should report nothing for consistent data', () => {
+    const result = findTagOrphans([createTag('HR')], [createTopic('t1', ['HR'])]);
+
+    expect(result.unusedTags).toEqual([]);
+    expect(result.unknownTagNames).toEqual([]);
+  });
+});
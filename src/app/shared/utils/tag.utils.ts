@@ -0,0 +1,96 @@
+import { Tag, Topic } from '../../core/models';
+
+/**
+ * Topic changes needed to assign a tag to exactly a given set of topics.
+ */
+export interface TagAssignmentChanges {
+  /** Updates in the format of BackendService.updateMultipleTopics */
+  updates: Array<{ topicId: string; changes: Partial<Topic> }>;
+  /** Number of topics the tag is added to */
+  added: number;
+  /** Number of topics the tag is removed from */
+  removed: number;
+}
+
+/**
+ * Computes the topic updates to assign a tag to exactly the selected topics:
+ * the tag is added where missing and removed from all other topics.
+ * Topics that already match the selection are not touched.
+ *
+ * @param topics All topics
+ * @param tagName Name of the tag to assign
+ * @param selectedTopicIds IDs of the topics that should have the tag
+ * @returns Updates and counts of added/removed assignments
+ */
+export function computeTagAssignment(
+  topics: Topic[],
+  tagName: string,
+  selectedTopicIds: Iterable<string>
+): TagAssignmentChanges {
+  const selected = new Set(selectedTopicIds);
+  const updates: TagAssignmentChanges['updates'] = [];
+  let added = 0;
+  let removed = 0;
+
+  for (const topic of topics) {
+    const tags = topic.tags || [];
+    const hasTag = tags.includes(tagName);
+    const shouldHaveTag = selected.has(topic.id);
+
+    if (shouldHaveTag && !hasTag) {
+      updates.push({ topicId: topic.id, changes: { tags: [...tags, tagName] } });
+      added++;
+    } else if (!shouldHaveTag && hasTag) {
+      updates.push({ topicId: topic.id, changes: { tags: tags.filter(t => t !== tagName) } });
+      removed++;
+    }
+  }
+
+  return { updates, added, removed };
+}
+
+/**
+ * Result of the orphan detection for tags.
+ */
+export interface TagOrphans {
+  /** Managed tags not used by any topic */
+  unusedTags: Tag[];
+  /** Tag names used by topics that match no managed tag, with usage count */
+  unknownTagNames: Array<{ name: string; count: number }>;
+}
+
+/**
+ * Counts how many topics use each tag name.
+ *
+ * @param topics All topics
+ * @returns Map of tag name to number of topics using it
+ */
+export function countTagUsage(topics: Topic[]): Map<string, number> {
+  const counts = new Map<string, number>();
+  for (const topic of topics) {
+    for (const tagName of new Set(topic.tags || [])) {
+      counts.set(tagName, (counts.get(tagName) || 0) + 1);
+    }
+  }
+  return counts;
+}
+
+/**
+ * Finds managed tags used by no topic and topic tag names without a managed tag.
+ *
+ * @param tags Managed tags
+ * @param topics All topics
+ * @returns Unused tags and unknown tag names (sorted by name)
+ */
+export function findTagOrphans(tags: Tag[], topics: Topic[]): TagOrphans {
+  const usage = countTagUsage(topics);
+  const managedNames = new Set(tags.map(t => t.name));
+
+  const unusedTags = tags.filter(tag => !usage.has(tag.name));
+  const unknownTagNames = [...usage.entries()]
+    .filter(([name]) => !managedNames.has(name))
+    .map(([name, count]) => ({ name, count }))
+    .sort((a, b) => a.name.localeCompare(b.name));
+
+  return { unusedTags, unknownTagNames };
+}
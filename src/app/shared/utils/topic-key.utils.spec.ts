@@ -0,0 +1,92 @@
+import { describe, it, expect } from 'vitest';
+import {
+  assignMissingTopicKeys,
+  findTopicByKey,
+  formatTopicKey,
+  getNextTopicNumber,
+  parseTopicKeyNumber
+} from './topic-key.utils';
+import { Datastore, Topic } from '../../core/models';
+
+const createTopic = (id: string, key?: string): Topic => ({
+  id,
+  header: `Topic ${id}`,
+  validity: { alwaysValid: true },
+  raci: { r1MemberId: 'm1', cMemberIds: [], iMemberIds: [] },
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  ...(key ? { key } : {})
+});
+
+const createDatastore = (topics: Topic[], nextTopicNumber?: number): Datastore => ({
+  schemaVersion: 1,
+  generatedAt: '2024-01-01T00:00:00.000Z',
+  revisionId: 1,
+  members: [],
+  topics,
+  ...(nextTopicNumber !== undefined ? { nextTopicNumber } : {})
+});
+
+describe('formatTopicKey / parseTopicKeyNumber', () => {
+  it('should round-trip a sequence number', () => {
+    expect(formatTopicKey(142)).toBe('RACI-142');
+    expect(parseTopicKeyNumber('RACI-142')).toBe(142);
+  });
+
+  it('should accept lower case and surrounding whitespace', () => {
+    expect(parseTopicKeyNumber(' raci-7 ')).toBe(7);
+  });
+
+  it('should return null for invalid keys', () => {
+    expect(parseTopicKeyNumber(undefined)).toBeNull();
+    expect(parseTopicKeyNumber('RACI-')).toBeNull();
+    expect(parseTopicKeyNumber('TASK-12')).toBeNull();
+  });
+});
+
+describe('getNextTopicNumber', () => {
+  it('should start at 1', () => {
+    expect(getNextTopicNumber(createDatastore([]))).toBe(1);
+  });
+
+  it('should use the stored counter', () => {
+    expect(getNextTopicNumber(createDatastore([createTopic('t1', 'RACI-3')], 10))).toBe(10);
+  });
+
+  it('should skip numbers already in use', () => {
+    expect(getNextTopicNumber(createDatastore([createTopic('t1', 'RACI-12')], 5))).toBe(13);
+  });
+});
+
+describe('assignMissingTopicKeys', () => {
+  it('should assign keys in list order', () => {
+    const result = assignMissingTopicKeys(createDatastore([createTopic('t1'), createTopic('t2', 'RACI-1'), createTopic('t3')]));
+
+    expect(result.topics.map(t => t.key)).toEqual(['RACI-2', 'RACI-1', 'RACI-3']);
+    expect(result.nextTopicNumber).toBe(4);
+  });
+
+  it('should reassign duplicate keys', () => {
+    const result = assignMissingTopicKeys(createDatastore([createTopic('t1', 'RACI-1'), createTopic('t2', 'raci-1')]));
+
+    expect(result.topics.map(t => t.key)).toEqual(['RACI-1', 'RACI-2']);
+  });
+
+  it('should return the same datastore if all keys are valid', () => {
+    const datastore = createDatastore([createTopic('t1', 'RACI-1'), createTopic('t2', 'RACI-2')]);
+
+    expect(assignMissingTopicKeys(datastore)).toBe(datastore);
+  });
+});
+
+describe('findTopicByKey', () => {
+  const topics = [createTopic('t1', 'RACI-1'), createTopic('t2', 'RACI-42')];
+
+  it('should find a topic case-insensitively', () => {
+    expect(findTopicByKey(topics, 'raci-42')?.id).toBe('t2');
+  });
+
+  it('should return undefined for unknown or invalid keys', () => {
+    expect(findTopicByKey(topics, 'RACI-5')).toBeUndefined();
+    expect(findTopicByKey(topics, 'foo')).toBeUndefined();
+  });
+});
@@ -0,0 +1,78 @@
+/**
+ * Human-readable topic keys (e.g. "RACI-142").
+ * Keys are assigned once from an increasing sequence number and never change.
+ */
+
+import { Datastore, Topic } from '../../core/models';
+
+export const TOPIC_KEY_PREFIX = 'RACI';
+
+const TOPIC_KEY_PATTERN = new RegExp(`^${TOPIC_KEY_PREFIX}-(\\d+)$`, 'i');
+
+/**
+ * Formats a topic key from its sequence number.
+ */
+export function formatTopicKey(sequenceNumber: number): string {
+  return `${TOPIC_KEY_PREFIX}-${sequenceNumber}`;
+}
+
+/**
+ * Returns the sequence number of a topic key, or null if the key is not valid.
+ */
+export function parseTopicKeyNumber(key: string | undefined): number | null {
+  const match = key?.trim().match(TOPIC_KEY_PATTERN);
+  return match ? Number(match[1]) : null;
+}
+
+/**
+ * Returns the next free sequence number.
+ * Uses the stored counter but never returns a number that is already in use.
+ */
+export function getNextTopicNumber(datastore: Datastore): number {
+  const highestUsed = datastore.topics.reduce(
+    (max, topic) => Math.max(max, parseTopicKeyNumber(topic.key) ?? 0),
+    0
+  );
+  return Math.max(datastore.nextTopicNumber ?? 1, highestUsed + 1);
+}
+
+/**
+ * Assigns keys to topics without a valid key and to topics whose key is already used by
+ * an earlier topic. Keys are assigned in list order, so the result is deterministic.
+ *
+ * @returns The datastore with keys and updated counter, or the same datastore if nothing changed
+ */
+export function assignMissingTopicKeys(datastore: Datastore): Datastore {
+  const usedKeys = new Set<string>();
+  let nextNumber = getNextTopicNumber(datastore);
+  let changed = false;
+
+  const topics = datastore.topics.map(topic => {
+    const keyNumber = parseTopicKeyNumber(topic.key);
+    const key = keyNumber !== null ? formatTopicKey(keyNumber) : null;
+    if (key && !usedKeys.has(key)) {
+      usedKeys.add(key);
+      return topic;
+    }
+    changed = true;
+    const newKey = formatTopicKey(nextNumber++);
+    usedKeys.add(newKey);
+    return { ...topic, key: newKey };
+  });
+
+  if (!changed) {
+    return datastore;
+  }
+  return { ...datastore, topics, nextTopicNumber: nextNumber };
+}
+
+/**
+ * Finds a topic by its key (case-insensitive).
+ */
+export function findTopicByKey(topics: Topic[], key: string): Topic | undefined {
+  const keyNumber = parseTopicKeyNumber(key);
+  if (keyNumber === null) {
+    return undefined;
+  }
+  return topics.find(topic => parseTopicKeyNumber(topic.key) === keyNumber);
+}
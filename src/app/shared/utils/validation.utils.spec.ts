@@ -1,5 +1,5 @@
 import { describe, it, expect } from 'vitest';
-import { isValidKeyword, sanitizeKeyword } from './validation.utils';
+import { isValidKeyword, sanitizeKeyword, normalizeTimestamp, isCanonicalTimestamp } from './validation.utils';
 
 describe('isValidKeyword', () => {
   it('should accept simple alphanumeric strings', () => {
@@ -106,3 +106,44 @@ describe('sanitizeKeyword', () => {
     expect(sanitizeKeyword('test @keyword# 123')).toBe('test_keyword_123');
   });
 });
+
+describe('normalizeTimestamp', () => {
+  it('should keep canonical timestamps unchanged', () => {
+    expect(normalizeTimestamp('2024-05-01T08:30:00.000Z')).toBe('2024-05-01T08:30:00.000Z');
+  });
+
+  it('should add missing milliseconds', () => {
+    expect(normalizeTimestamp('2024-05-01T08:30:00Z')).toBe('2024-05-01T08:30:00.000Z');
+  });
+
+  it('should convert offsets to UTC', () => {
+    expect(normalizeTimestamp('2024-05-01T10:30:00+02:00')).toBe('2024-05-01T08:30:00.000Z');
+    expect(normalizeTimestamp('2024-05-01T10:30:00+0200')).toBe('2024-05-01T08:30:00.000Z');
+  });
+
+  it('should truncate sub-millisecond precision', () => {
+    expect(normalizeTimestamp('2024-05-01T08:30:00.123456Z')).toBe('2024-05-01T08:30:00.123Z');
+  });
+
+  it('should interpret SQLite datetime format as UTC', () => {
+    expect(normalizeTimestamp('2024-05-01 08:30:00')).toBe('2024-05-01T08:30:00.000Z');
+  });
+
+  it('should reject invalid timestamps', () => {
+    expect(normalizeTimestamp('')).toBeUndefined();
+    expect(normalizeTimestamp(undefined)).toBeUndefined();
+    expect(normalizeTimestamp('01.05.2024 08:30')).toBeUndefined();
+    expect(normalizeTimestamp('2024-05-01')).toBeUndefined();
+    expect(normalizeTimestamp('2024-02-30T08:30:00Z')).toBeUndefined();
+    expect(normalizeTimestamp('2024-05-01T25:00:00Z')).toBeUndefined();
+  });
+});
+
+describe('isCanonicalTimestamp', () => {
+  it('should accept only Date.toISOString() format', () => {
+    expect(isCanonicalTimestamp(new Date().toISOString())).toBe(true);
+    expect(isCanonicalTimestamp('2024-05-01T08:30:00Z')).toBe(false);
+    expect(isCanonicalTimestamp('2024-05-01 08:30:00')).toBe(false);
+    expect(isCanonicalTimestamp(undefined)).toBe(false);
+  });
+});
@@ -77,3 +77,53 @@ export function normalizeHexColor(value: string | undefined): string | undefined
   }
   return value.startsWith('#') ? value : `#${value}`;
 }
+
+/**
+ * Regular expression for timestamps accepted by normalizeTimestamp.
+ * Date and time separated by "T" or a space (SQLite datetime() format),
+ * optional fractional seconds, optional "Z" or numeric UTC offset.
+ */
+const TIMESTAMP_REGEX =
+  /^(\d{4})-(\d{2})-(\d{2})[T ](\d{2}):(\d{2}):(\d{2})(\.\d+)?(Z|[+-]\d{2}:?\d{2})?$/i;
+
+/**
+ * Checks whether a timestamp is in canonical form:
+ * RFC 3339 in UTC with millisecond precision, as produced by Date.toISOString().
+ *
+ * @param value The timestamp to check
+ * @returns true if the timestamp is canonical
+ */
+export function isCanonicalTimestamp(value: string | undefined): boolean {
+  return !!value && normalizeTimestamp(value) === value;
+}
+
+/**
+ * Normalizes a timestamp to canonical RFC 3339 UTC form (e.g. "2024-05-01T08:30:00.000Z").
+ * Timestamps without zone designator are interpreted as UTC.
+ * Canonical timestamps sort correctly as plain strings.
+ *
+ * @param value The timestamp to normalize
+ * @returns The normalized timestamp, or undefined if the value is not a valid timestamp
+ */
+export function normalizeTimestamp(value: string | undefined): string | undefined {
+  if (!value) {
+    return undefined;
+  }
+  const match = TIMESTAMP_REGEX.exec(value.trim());
+  if (!match) {
+    return undefined;
+  }
+  const [, year, month, day, hour, minute, second, fraction = '', zone = 'Z'] = match;
+  const offset = zone.toUpperCase() === 'Z' ? 'Z' : zone.replace(/^([+-]\d{2}):?(\d{2})$/, '$1:$2');
+  const millis = fraction ? fraction.substring(0, 4).padEnd(4, '0') : '';
+  const date = new Date(`${year}-${month}-${day}T${hour}:${minute}:${second}${millis}${offset}`);
+  if (isNaN(date.getTime())) {
+    return undefined;
+  }
+  // Reject dates that roll over, e.g. 2024-02-30
+  const calendarDay = new Date(Date.UTC(Number(year), Number(month) - 1, Number(day)));
+  if (calendarDay.getUTCDate() !== Number(day) || calendarDay.getUTCMonth() + 1 !== Number(month)) {
+    return undefined;
+  }
+  return date.toISOString();
+}
@@ -0,0 +1,98 @@
+import { describe, it, expect } from 'vitest';
+import {
+  parseValidityDate,
+  toValidityDateString,
+  formatValidityDate,
+  getValidityStatus,
+  isValidityActive
+} from './validity.utils';
+
+describe('parseValidityDate', () => {
+  it('should parse date strings as local midnight', () => {
+    const date = parseValidityDate('2024-05-01');
+    expect(date).not.toBeNull();
+    expect(date!.getFullYear()).toBe(2024);
+    expect(date!.getMonth()).toBe(4);
+    expect(date!.getDate()).toBe(1);
+    expect(date!.getHours()).toBe(0);
+  });
+
+  it('should use the date part of ISO timestamps', () => {
+    const date = parseValidityDate('2024-05-01T22:00:00.000Z');
+    expect(date!.getDate()).toBe(1);
+  });
+
+  it('should reject missing and malformed values', () => {
+    expect(parseValidityDate(undefined)).toBeNull();
+    expect(parseValidityDate('')).toBeNull();
+    expect(parseValidityDate('01.05.2024')).toBeNull();
+    expect(parseValidityDate('2024-5-1')).toBeNull();
+  });
+
+  it('should reject dates that do not exist', () => {
+    expect(parseValidityDate('2024-02-30')).toBeNull();
+    expect(parseValidityDate('2023-13-01')).toBeNull();
+  });
+});
+
+describe('toValidityDateString', () => {
+  it('should use the local calendar day', () => {
+    expect(toValidityDateString(new Date(2024, 4, 1))).toBe('2024-05-01');
+    expect(toValidityDateString(new Date(2024, 11, 31, 23, 59))).toBe('2024-12-31');
+  });
+
+  it('should return undefined for no date', () => {
+    expect(toValidityDateString(null)).toBeUndefined();
+  });
+
+  it('should round-trip with parseValidityDate', () => {
+    expect(toValidityDateString(parseValidityDate('2024-03-31'))).toBe('2024-03-31');
+  });
+});
+
+describe('formatValidityDate', () => {
+  it('should format in German notation', () => {
+    expect(formatValidityDate('2024-05-31')).toBe('31.5.2024');
+  });
+
+  it('should return an empty string for invalid values', () => {
+    expect(formatValidityDate('invalid')).toBe('');
+  });
+});
+
+describe('getValidityStatus', () => {
+  const range = { alwaysValid: false, validFrom: '2024-05-01', validTo: '2024-05-31' };
+
+  it('should return always for always valid topics', () => {
+    expect(getValidityStatus({ alwaysValid: true })).toBe('always');
+  });
+
+  it('should be future before the start day', () => {
+    expect(getValidityStatus(range, new Date(2024, 3, 30, 23, 59))).toBe('future');
+  });
+
+  it('should be valid from the start of the first day', () => {
+    expect(getValidityStatus(range, new Date(2024, 4, 1, 0, 0))).toBe('valid');
+  });
+
+  it('should be valid during the whole last day', () => {
+    expect(getValidityStatus(range, new Date(2024, 4, 31, 23, 59))).toBe('valid');
+  });
+
+  it('should be expired from the day after validTo', () => {
+    expect(getValidityStatus(range, new Date(2024, 5, 1, 0, 0))).toBe('expired');
+  });
+
+  it('should treat open ranges as valid', () => {
+    expect(getValidityStatus({ alwaysValid: false, validFrom: '2024-05-01' }, new Date(2030, 0, 1))).toBe('valid');
+  });
+});
+
+describe('isValidityActive', () => {
+  it('should be true for always and valid topics only', () => {
+    const range = { alwaysValid: false, validFrom: '2024-05-01', validTo: '2024-05-31' };
+    expect(isValidityActive({ alwaysValid: true })).toBe(true);
+    expect(isValidityActive(range, new Date(2024, 4, 15))).toBe(true);
+    expect(isValidityActive(range, new Date(2024, 6, 1))).toBe(false);
+  });
+});
@@ -0,0 +1,103 @@
+/**
+ * Shared topic validity utilities.
+ *
+ * Validity dates are stored as calendar dates ("YYYY-MM-DD") and evaluated in the
+ * local time zone of the browser: a topic is valid from the start of its validFrom
+ * day up to and including the whole validTo day. Parsing a date-only string with
+ * `new Date()` would interpret it as UTC midnight and shift the boundaries by the
+ * UTC offset, so all validity checks must go through these helpers.
+ */
+
+import { TopicValidity } from '../../core/models';
+
+/**
+ * Evaluated validity state of a topic.
+ * - always: topic is marked as always valid
+ * - valid: today is within the validity range
+ * - future: validity starts after today
+ * - expired: validity ended before today
+ */
+export type ValidityStatus = 'always' | 'valid' | 'future' | 'expired';
+
+const DATE_PATTERN = /^(\d{4})-(\d{2})-(\d{2})(?:$|T)/;
+
+/**
+ * Parses a stored validity date as a local calendar date (00:00 local time).
+ * Accepts "YYYY-MM-DD" and, for older data, full ISO timestamps (only the date part is used).
+ *
+ * @param value Stored date string
+ * @returns Local midnight of that day, or null if missing or not a real calendar date
+ */
+export function parseValidityDate(value: string | undefined | null): Date | null {
+  if (!value) return null;
+  const match = DATE_PATTERN.exec(value.trim());
+  if (!match) return null;
+
+  const year = Number(match[1]);
+  const month = Number(match[2]) - 1;
+  const day = Number(match[3]);
+  const date = new Date(year, month, day);
+
+  // Reject dates that roll over, e.g. 2024-02-30
+  if (date.getFullYear() !== year || date.getMonth() !== month || date.getDate() !== day) {
+    return null;
+  }
+  return date;
+}
+
+/**
+ * Formats a local date as a validity date string ("YYYY-MM-DD").
+ * Uses the local calendar day, not the UTC day.
+ *
+ * @param date Date selected in the UI
+ * @returns Date string, or undefined if no date is given
+ */
+export function toValidityDateString(date: Date | null | undefined): string | undefined {
+  if (!date) return undefined;
+  const month = String(date.getMonth() + 1).padStart(2, '0');
+  const day = String(date.getDate()).padStart(2, '0');
+  return `${date.getFullYear()}-${month}-${day}`;
+}
+
+/**
+ * Formats a stored validity date for display (German format, e.g. "31.05.2024").
+ *
+ * @param value Stored date string
+ * @returns Formatted date, or an empty string if the value is invalid
+ */
+export function formatValidityDate(value: string | undefined | null): string {
+  const date = parseValidityDate(value);
+  return date ? date.toLocaleDateString('de-DE') : '';
+}
+
+/**
+ * Evaluates the validity of a topic at a given point in time.
+ *
+ * @param validity Topic validity
+ * @param now Point in time to evaluate (default: current time)
+ * @returns Validity status
+ */
+export function getValidityStatus(validity: TopicValidity, now: Date = new Date()): ValidityStatus {
+  if (validity.alwaysValid) return 'always';
+
+  const validFrom = parseValidityDate(validity.validFrom);
+  const validTo = parseValidityDate(validity.validTo);
+
+  if (validFrom && now < validFrom) return 'future';
+
+  if (validTo) {
+    // validTo is inclusive: the topic expires at the start of the following day
+    const endExclusive = new Date(validTo.getFullYear(), validTo.getMonth(), validTo.getDate() + 1);
+    if (now >= endExclusive) return 'expired';
+  }
+
+  return 'valid';
+}
+
+/**
+ * Checks whether a topic is currently in effect (always valid or within its range).
+ */
+export function isValidityActive(validity: TopicValidity, now: Date = new Date()): boolean {
+  const status = getValidityStatus(validity, now);
+  return status === 'always' || status === 'valid';
+}
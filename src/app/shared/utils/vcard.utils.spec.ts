@@ -0,0 +1,73 @@
+import { describe, it, expect } from 'vitest';
+import { escapeVCardValue, toVCard, toVCards } from './vcard.utils';
+import { TeamMember } from '../../core/models';
+
+const createMember = (overrides?: Partial<TeamMember>): TeamMember => ({
+  id: 'm1',
+  displayName: 'Hans Müller',
+  email: 'hans.mueller@example.com',
+  active: true,
+  updatedAt: '2024-01-01T00:00:00.000Z',
+  ...overrides
+});
+
+describe('vcard.utils', () => {
+  describe('escapeVCardValue', () => {
+    it('should escape special characters', () => {
+      expect(escapeVCardValue('a,b;c\\d\ne')).toBe('a\\,b\\;c\\\\d\\ne');
+    });
+  });
+
+  describe('toVCard', () => {
+    it('should create a vCard 3.0 with CRLF line endings', () => {
+      const vcard = toVCard(createMember());
+
+      expect(vcard).toBe([
+        'BEGIN:VCARD',
+        'VERSION:3.0',
+        'UID:m1',
+        'FN:Hans Müller',
+        'N:Müller;Hans;;;',
+        'EMAIL;TYPE=INTERNET,WORK:hans.mueller@example.com',
+        'REV:2024-01-01T00:00:00.000Z',
+        'END:VCARD',
+        ''
+      ].join('\r\n'));
+    });
+
+    it('should support "Family, Given" names', () => {
+      const vcard = toVCard(createMember({ displayName: 'Müller, Hans' }));
+
+      expect(vcard).toContain('\r\nFN:Müller\\, Hans\r\n');
+      expect(vcard).toContain('\r\nN:Müller;Hans;;;\r\n');
+    });
+
+    it('should omit the email if not set', () => {
+      expect(toVCard(createMember({ email: undefined }))).not.toContain('EMAIL');
+    });
+
+    it('should add organization and tags as categories', () => {
+      const vcard = toVCard(createMember({ tags: ['IT', 'Support'] }), 'Team A');
+
+      expect(vcard).toContain('\r\nORG:Team A\r\n');
+      expect(vcard).toContain('\r\nCATEGORIES:IT,Support\r\n');
+    });
+
+    it('should fold long lines', () => {
+      const vcard = toVCard(createMember({ displayName: 'A'.repeat(100) + ' B' }));
+
+      const lines = vcard.split('\r\n');
+      expect(lines.every(line => line.length <= 75)).toBe(true);
+      expect(lines.some(line => line.startsWith(' '))).toBe(true);
+    });
+  });
+
+  describe('toVCards', () => {
+    it('should concatenate one vCard per member', () => {
+      const result = toVCards([createMember(), createMember({ id: 'm2', displayName: 'Erika Muster' })]);
+
+      expect(result.match(/BEGIN:VCARD/g)).toHaveLength(2);
+      expect(result).toContain('UID:m2');
+    });
+  });
+});
@@ -0,0 +1,90 @@
+/**
+ * vCard (version 3.0, RFC 2426) export for team members.
+ * Version 3.0 is used because Outlook imports it reliably.
+ */
+
+import { TeamMember } from '../../core/models';
+
+const MAX_LINE_LENGTH = 75;
+
+/**
+ * Escapes a text value for use in a vCard property.
+ */
+export function escapeVCardValue(value: string): string {
+  return value
+    .replace(/\\/g, '\\\\')
+    .replace(/\r?\n/g, '\\n')
+    .replace(/,/g, '\\,')
+    .replace(/;/g, '\\;');
+}
+
+/**
+ * Folds a content line to at most 75 characters per line (continuation lines start with a space).
+ */
+function foldLine(line: string): string {
+  if (line.length <= MAX_LINE_LENGTH) {
+    return line;
+  }
+  const parts = [line.slice(0, MAX_LINE_LENGTH)];
+  for (let i = MAX_LINE_LENGTH; i < line.length; i += MAX_LINE_LENGTH - 1) {
+    parts.push(' ' + line.slice(i, i + MAX_LINE_LENGTH - 1));
+  }
+  return parts.join('\r\n');
+}
+
+/**
+ * Splits a display name into family and given name.
+ * Supports "Family, Given" and "Given Family".
+ */
+function splitName(displayName: string): { family: string; given: string } {
+  const name = displayName.trim();
+  const commaIndex = name.indexOf(',');
+  if (commaIndex > 0) {
+    return { family: name.slice(0, commaIndex).trim(), given: name.slice(commaIndex + 1).trim() };
+  }
+  const spaceIndex = name.lastIndexOf(' ');
+  if (spaceIndex > 0) {
+    return { family: name.slice(spaceIndex + 1), given: name.slice(0, spaceIndex) };
+  }
+  return { family: name, given: '' };
+}
+
+/**
+ * Creates a vCard for a team member.
+ *
+ * @param member The team member
+ * @param organization Optional organization/team name (ORG)
+ * @returns The vCard text with CRLF line endings
+ */
+export function toVCard(member: TeamMember, organization?: string): string {
+  const { family, given } = splitName(member.displayName);
+  const lines = [
+    'BEGIN:VCARD',
+    'VERSION:3.0',
+    `UID:${member.id}`,
+    `FN:${escapeVCardValue(member.displayName.trim())}`,
+    `N:${escapeVCardValue(family)};${escapeVCardValue(given)};;;`,
+  ];
+  if (member.email?.trim()) {
+    lines.push(`EMAIL;TYPE=INTERNET,WORK:${member.email.trim()}`);
+  }
+  if (organization?.trim()) {
+    lines.push(`ORG:${escapeVCardValue(organization.trim())}`);
+  }
+  if (member.tags && member.tags.length > 0) {
+    lines.push(`CATEGORIES:${member.tags.map(escapeVCardValue).join(',')}`);
+  }
+  lines.push(`REV:${member.updatedAt}`, 'END:VCARD');
+  return lines.map(foldLine).join('\r\n') + '\r\n';
+}
+
+/**
+ * Creates a single .vcf file content with one vCard per member.
+ *
+ * @param members The team members
+ * @param organization Optional organization/team name (ORG)
+ * @returns The concatenated vCards
+ */
+export function toVCards(members: TeamMember[], organization?: string): string {
+  return members.map(member => toVCard(member, organization)).join('');
+}